@@ -5,7 +5,7 @@ use std::time::Instant;
 use tokio::sync::mpsc::unbounded_channel;
 use tokio::sync::Semaphore;
 
-#[derive(InfluxDbWriteable, Clone)]
+#[derive(InfluxDbWriteable)]
 struct WeatherReading {
     time: DateTime<Utc>,
     humidity: i32,
@@ -33,7 +33,9 @@ async fn main() {
         let client_task = client.clone();
         let tx_task = tx.clone();
         tokio::spawn(async move {
-            let res = client_task.query(&m.into_query("weather")).await;
+            let res = client_task
+                .query((&m).try_into_query("weather").unwrap())
+                .await;
             let _ = tx_task.send(res);
             drop(permit);
         });
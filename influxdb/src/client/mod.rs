@@ -187,19 +187,12 @@ impl Client {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use influxdb::{Client, InfluxDbWriteable, Query, Timestamp};
-    /// use std::time::{SystemTime, UNIX_EPOCH};
+    /// use influxdb::{Client, InfluxDbWriteable, Precision, Query, Timestamp};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), influxdb::Error> {
-    /// let start = SystemTime::now();
-    /// let since_the_epoch = start
-    ///     .duration_since(UNIX_EPOCH)
-    ///     .expect("Time went backwards")
-    ///     .as_millis();
-    ///
     /// let client = Client::new("http://localhost:8086", "test");
-    /// let query = Timestamp::Milliseconds(since_the_epoch)
+    /// let query = Timestamp::now(Precision::Milliseconds)
     ///     .try_into_query("weather")
     ///     .unwrap()
     ///     .add_field("temperature", 82);
@@ -217,6 +210,33 @@ impl Client {
     pub async fn query<Q>(&self, q: Q) -> Result<String, Error>
     where
         Q: Query,
+    {
+        let body = self.send(&q).await?;
+        check_body(&body)?;
+        Ok(body)
+    }
+
+    /// Sends a query and returns the response body, or an error if the request failed or
+    /// InfluxDB responded with a non-2xx status code
+    pub(crate) async fn send<Q>(&self, q: &Q) -> Result<String, Error>
+    where
+        Q: Query + ?Sized,
+    {
+        self.request(q, false)
+            .await?
+            .text()
+            .await
+            .map_err(|_| Error::DeserializationError {
+                error: "response could not be converted to UTF-8".into(),
+            })
+    }
+
+    /// Sends a query and returns the response, or an error if the request failed or InfluxDB
+    /// responded with a non-2xx status code. `chunked` asks InfluxDB to return the results of
+    /// read queries in chunks of newline-separated JSON objects.
+    pub(crate) async fn request<Q>(&self, q: &Q, chunked: bool) -> Result<HttpResponse, Error>
+    where
+        Q: Query + ?Sized,
     {
         let query = q.build().map_err(|err| Error::InvalidQueryError {
             error: err.to_string(),
@@ -227,9 +247,16 @@ impl Client {
             QueryType::ReadQuery => {
                 let read_query = query.get();
                 let url = &format!("{}/query", &self.url);
-                parameters.insert("q", read_query.clone());
+                let read_only = is_read_only(&read_query);
+                parameters.insert("q", read_query);
+                if let Some(epoch) = q.get_epoch() {
+                    parameters.insert("epoch", epoch.to_string());
+                }
+                if chunked {
+                    parameters.insert("chunked", "true".to_string());
+                }
 
-                if read_query.contains("SELECT") || read_query.contains("SHOW") {
+                if read_only {
                     self.client.get(url).query(&parameters)
                 } else {
                     self.client.post(url).query(&parameters)
@@ -237,7 +264,6 @@ impl Client {
             }
             QueryType::WriteQuery(precision) => {
                 let url = &format!("{}/write", &self.url);
-                let mut parameters = self.parameters.as_ref().clone();
                 parameters.insert("precision", precision);
 
                 self.client.post(url).body(query.get()).query(&parameters)
@@ -252,21 +278,7 @@ impl Client {
             })
             .await?;
         check_status(&res)?;
-
-        let body = res.text();
-
-        let s = body.await.map_err(|_| Error::DeserializationError {
-            error: "response could not be converted to UTF-8".into(),
-        })?;
-
-        // todo: improve error parsing without serde
-        if s.contains("\"error\"") || s.contains("\"Error\"") {
-            return Err(Error::DatabaseError {
-                error: format!("influxdb error: {s:?}"),
-            });
-        }
-
-        Ok(s)
+        Ok(res)
     }
 
     fn auth_if_needed(&self, rb: RequestBuilder) -> RequestBuilder {
@@ -278,7 +290,7 @@ impl Client {
     }
 }
 
-pub(crate) fn check_status(res: &HttpResponse) -> Result<(), Error> {
+fn check_status(res: &HttpResponse) -> Result<(), Error> {
     let status = res.status();
     if !status.is_success() {
         return Err(Error::ApiError(status.into()));
@@ -286,9 +298,72 @@ pub(crate) fn check_status(res: &HttpResponse) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns an error if InfluxDB reported an error for the query or any of its statements
+#[cfg(feature = "serde")]
+fn check_body(body: &str) -> Result<(), Error> {
+    crate::integrations::serde_integration::parse_response(body, None)?
+        .try_for_each(|result| result.check())
+}
+
+/// Returns an error if InfluxDB reported an error for the query or any of its statements
+#[cfg(not(feature = "serde"))]
+fn check_body(body: &str) -> Result<(), Error> {
+    if body.contains("\"error\"") || body.contains("\"Error\"") {
+        return Err(Error::DatabaseError {
+            error: format!("influxdb error: {body:?}"),
+        });
+    }
+    Ok(())
+}
+
+/// Whether all statements of a read query only read data and can be sent with `GET`. All other
+/// queries, including `SELECT ... INTO`, have to be sent with `POST`.
+fn is_read_only(query: &str) -> bool {
+    // the lowercase keywords of every statement, skipping quoted strings and identifiers
+    let mut statements = vec![Vec::new()];
+    let mut word = String::new();
+    let mut quote = None;
+    let mut chars = query.chars();
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            if c == '\\' {
+                chars.next();
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        if c.is_alphanumeric() || c == '_' {
+            word.extend(c.to_lowercase());
+            continue;
+        }
+        let statement = statements.last_mut().unwrap();
+        if !word.is_empty() {
+            statement.push(std::mem::take(&mut word));
+        }
+        match c {
+            '\'' | '"' => quote = Some(c),
+            ';' => statements.push(Vec::new()),
+            _ => {}
+        }
+    }
+    if !word.is_empty() {
+        statements.last_mut().unwrap().push(word);
+    }
+
+    statements
+        .iter()
+        .filter(|words| !words.is_empty())
+        .all(|words| match words[0].as_str() {
+            "show" => true,
+            "select" => !words.iter().any(|word| word == "into"),
+            _ => false,
+        })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Client;
+    use super::{is_read_only, Client};
     use indoc::indoc;
 
     #[test]
@@ -334,4 +409,21 @@ mod tests {
         assert_eq!(with_auth.parameters.get("db").unwrap(), "database");
         assert_eq!(with_auth.token.unwrap(), "token");
     }
+
+    #[test]
+    fn test_is_read_only() {
+        assert!(is_read_only("SELECT * FROM weather"));
+        assert!(is_read_only("show databases; select * from weather"));
+        assert!(!is_read_only("SELECT * INTO weather_copy FROM weather"));
+        assert!(!is_read_only("CREATE DATABASE test"));
+        assert!(!is_read_only(
+            "SELECT * FROM weather; DROP MEASUREMENT weather"
+        ));
+        assert!(is_read_only(r#"SELECT * FROM "a; b""#));
+        assert!(is_read_only("SELECT * FROM weather WHERE note = 'into'"));
+        assert!(is_read_only(
+            r#"SELECT "into" FROM weather WHERE note = 'it\'s; into'"#
+        ));
+        assert!(!is_read_only(r#"SELECT * INTO "copy" FROM "a; b""#));
+    }
 }
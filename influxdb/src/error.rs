@@ -1,5 +1,6 @@
 //! Errors that might happen in the crate
 
+use std::sync::Arc;
 use thiserror::Error;
 
 #[derive(Debug, Eq, PartialEq, Error)]
@@ -34,14 +35,42 @@ pub enum Error {
     ConnectionError { error: String },
 }
 
-#[cfg(feature = "chrono")]
 #[derive(Clone, Copy, Debug, Error)]
-#[error("The timestamp is too large to fit into an i64.")]
+#[error("The timestamp is too large to be represented by the target type.")]
 pub struct TimestampTooLargeError(pub(crate) ());
 
-#[cfg(any(feature = "chrono", feature = "time"))]
+#[derive(Clone, Copy, Debug, Error)]
+#[error(
+    "The timestamp cannot be represented in the requested precision without losing information."
+)]
+pub struct PrecisionLossError(pub(crate) ());
+
+/// An error returned when converting a decimal that is out of the range of `f64` into a float
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+#[derive(Clone, Copy, Debug, Error)]
+#[error("The decimal is too large to be represented as a float.")]
+pub struct DecimalRangeError(pub(crate) ());
+
+/// An error returned by the conversion function of a field, see
+/// `#[influxdb(serialize_with = "...")]`
+#[derive(Clone, Debug, Error)]
+#[error(transparent)]
+pub struct ConversionError(Arc<dyn std::error::Error + Send + Sync>);
+
+impl ConversionError {
+    pub fn new<E>(error: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self(Arc::from(error.into()))
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
 #[derive(Clone, Copy, Debug, Error)]
 pub enum TimeTryFromError<T, I> {
+    #[error("{0}")]
     TimeError(#[source] T),
+    #[error("{0}")]
     IntError(#[source] I),
 }
@@ -0,0 +1,423 @@
+//! Apache Arrow Integration for InfluxDB. Converts query results into [`RecordBatch`]es.
+//!
+//! The series of a [`ResultTable`] are converted into a single [`RecordBatch`]:
+//!
+//!  * The `time` column is a `Timestamp(Nanosecond, "UTC")` column.
+//!  * The tags of the series, which InfluxDB returns for `GROUP BY` queries, are dictionary
+//!    encoded string columns.
+//!  * The types of the other columns are inferred from their values, and columns with values
+//!    of different types become string columns. InfluxDB returns floats without a fractional
+//!    part as integers, so pass the types of the fields from a `SHOW FIELD KEYS` query to
+//!    [`ResultTable::to_record_batch_with_field_types`] or
+//!    [`Client::query_record_batch_with_field_types`] to get the exact types, see
+//!    [`field_types`].
+//!
+//! Only the JSON results of InfluxDB 1.x compatible queries are supported.
+//!
+//! ```rust,no_run
+//! use influxdb::{Client, ReadQuery};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), influxdb::Error> {
+//! let client = Client::new("http://localhost:8086", "test");
+//! let batch = client
+//!     .query_record_batch(ReadQuery::new("SELECT * FROM weather GROUP BY location"))
+//!     .await?;
+//! println!("{} rows", batch.num_rows());
+//! # Ok(())
+//! # }
+//! ```
+
+use arrow::array::{
+    ArrayRef, BooleanBuilder, Float64Builder, Int64Builder, NullArray, StringBuilder,
+    StringDictionaryBuilder, TimestampNanosecondBuilder, UInt64Builder,
+};
+use arrow::datatypes::{DataType, Field, Int32Type, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::integrations::serde_integration::table::{
+    self, combined_columns, combined_values, ColumnKind, ValueType,
+};
+use crate::integrations::serde_integration::{DynamicSeries, FieldType, FieldValue, ResultTable};
+use crate::{Client, Error, ReadQuery};
+
+/// Returns the Arrow types of the fields listed by a `SHOW FIELD KEYS` query
+pub fn field_types(show_field_keys: &ResultTable) -> HashMap<String, DataType> {
+    table::field_types(show_field_keys)
+        .into_iter()
+        .map(|(name, field_type)| {
+            let data_type = match field_type {
+                FieldType::Float => DataType::Float64,
+                FieldType::Integer => DataType::Int64,
+                FieldType::Unsigned => DataType::UInt64,
+                FieldType::String => DataType::Utf8,
+                FieldType::Boolean => DataType::Boolean,
+            };
+            (name, data_type)
+        })
+        .collect()
+}
+
+impl ResultTable {
+    /// Converts all series into a single [`RecordBatch`], inferring the types of the columns
+    /// from their values, see the [module documentation](crate::integrations::arrow_integration)
+    pub fn to_record_batch(&self) -> Result<RecordBatch, Error> {
+        self.to_record_batch_with_field_types(&HashMap::new())
+    }
+
+    /// Converts all series into a single [`RecordBatch`], using the given types for fields,
+    /// e.g. from [`field_types`]
+    pub fn to_record_batch_with_field_types(
+        &self,
+        field_types: &HashMap<String, DataType>,
+    ) -> Result<RecordBatch, Error> {
+        record_batch(&self.series, field_types)
+    }
+}
+
+impl DynamicSeries {
+    /// Converts this series into a [`RecordBatch`], see [`ResultTable::to_record_batch`]
+    pub fn to_record_batch(&self) -> Result<RecordBatch, Error> {
+        record_batch(std::slice::from_ref(self), &HashMap::new())
+    }
+}
+
+impl Client {
+    /// Sends a [`ReadQuery`] and converts the result of its first statement into a
+    /// [`RecordBatch`], see [`ResultTable::to_record_batch`]
+    pub async fn query_record_batch(&self, q: ReadQuery) -> Result<RecordBatch, Error> {
+        self.query_record_batch_with_field_types(q, &HashMap::new())
+            .await
+    }
+
+    /// Sends a [`ReadQuery`] and converts the result of its first statement into a
+    /// [`RecordBatch`], using the given types for fields, see
+    /// [`ResultTable::to_record_batch_with_field_types`]
+    pub async fn query_record_batch_with_field_types(
+        &self,
+        q: ReadQuery,
+        field_types: &HashMap<String, DataType>,
+    ) -> Result<RecordBatch, Error> {
+        self.json_query(q)
+            .await?
+            .next_table()?
+            .to_record_batch_with_field_types(field_types)
+    }
+}
+
+fn record_batch(
+    series: &[DynamicSeries],
+    field_types: &HashMap<String, DataType>,
+) -> Result<RecordBatch, Error> {
+    let columns = combined_columns(series)
+        .into_iter()
+        .map(|column| {
+            // Only the values of inferred string columns are stringified, the values of string
+            // fields in `field_types` must be strings
+            let stringify = column.kind == ColumnKind::Field(ValueType::Text)
+                && !field_types.contains_key(column.name);
+            let data_type = match column.kind {
+                ColumnKind::Time => DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+                ColumnKind::Tag => {
+                    DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+                }
+                ColumnKind::Field(value_type) => match field_types.get(column.name) {
+                    Some(data_type) => data_type.clone(),
+                    None => match value_type {
+                        ValueType::Null => DataType::Null,
+                        ValueType::Boolean => DataType::Boolean,
+                        ValueType::Float => DataType::Float64,
+                        ValueType::SignedInteger => DataType::Int64,
+                        ValueType::UnsignedInteger => DataType::UInt64,
+                        ValueType::Text => DataType::Utf8,
+                    },
+                },
+            };
+            (column.name, data_type, stringify)
+        })
+        .collect::<Vec<_>>();
+
+    let arrays = columns
+        .iter()
+        .map(|(name, data_type, stringify)| build_array(series, name, data_type, *stringify))
+        .collect::<Result<Vec<_>, _>>()?;
+    let schema = Schema::new(
+        columns
+            .iter()
+            .map(|(name, data_type, _)| Field::new(*name, data_type.clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    RecordBatch::try_new(Arc::new(schema), arrays).map_err(|err| Error::DeserializationError {
+        error: format!("could not build record batch: {err}"),
+    })
+}
+
+/// Builds the array of the column `name`, converting every value to a string if `stringify` is
+/// set and `data_type` is `Utf8`
+fn build_array(
+    series: &[DynamicSeries],
+    name: &str,
+    data_type: &DataType,
+    stringify: bool,
+) -> Result<ArrayRef, Error> {
+    let values = combined_values(series, name);
+    let mismatch = |value: &FieldValue| Error::DeserializationError {
+        error: format!("could not convert {value:?} in column {name} to {data_type}"),
+    };
+
+    let array: ArrayRef = match data_type {
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            let mut builder = TimestampNanosecondBuilder::new().with_data_type(data_type.clone());
+            for value in values {
+                match value.as_ref() {
+                    FieldValue::Null => builder.append_null(),
+                    FieldValue::Time(time) => builder.append_value(
+                        time.checked_nanos()
+                            .and_then(|nanos| i64::try_from(nanos).ok())
+                            .ok_or_else(|| mismatch(&value))?,
+                    ),
+                    value => return Err(mismatch(value)),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Dictionary(key, value)
+            if **key == DataType::Int32 && **value == DataType::Utf8 =>
+        {
+            let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+            for value in values {
+                match value.as_ref() {
+                    FieldValue::Null => builder.append_null(),
+                    value => builder.append_value(value.to_string()),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Null => Arc::new(NullArray::new(values.count())),
+        DataType::Boolean => {
+            let mut builder = BooleanBuilder::new();
+            for value in values {
+                match value.as_ref() {
+                    FieldValue::Null => builder.append_null(),
+                    value => builder.append_value(value.as_bool().ok_or_else(|| mismatch(value))?),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Int64 => {
+            let mut builder = Int64Builder::new();
+            for value in values {
+                match value.as_ref() {
+                    FieldValue::Null => builder.append_null(),
+                    value => builder.append_value(value.as_i64().ok_or_else(|| mismatch(value))?),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::UInt64 => {
+            let mut builder = UInt64Builder::new();
+            for value in values {
+                match value.as_ref() {
+                    FieldValue::Null => builder.append_null(),
+                    value => builder.append_value(value.as_u64().ok_or_else(|| mismatch(value))?),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Float64 => {
+            let mut builder = Float64Builder::new();
+            for value in values {
+                match value.as_ref() {
+                    FieldValue::Null => builder.append_null(),
+                    value => builder.append_value(value.as_f64().ok_or_else(|| mismatch(value))?),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Utf8 => {
+            let mut builder = StringBuilder::new();
+            for value in values {
+                match value.as_ref() {
+                    FieldValue::Null => builder.append_null(),
+                    value if stringify => builder.append_value(value.to_string()),
+                    value => builder.append_value(value.as_str().ok_or_else(|| mismatch(value))?),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        data_type => {
+            return Err(Error::DeserializationError {
+                error: format!("unsupported type {data_type} for column {name}"),
+            })
+        }
+    };
+    Ok(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::field_types;
+    use crate::integrations::serde_integration::{DatabaseQueryResult, ResultTable};
+    use arrow::array::{
+        Array, AsArray, BooleanArray, DictionaryArray, Float64Array, Int64Array, StringArray,
+        TimestampNanosecondArray,
+    };
+    use arrow::datatypes::{DataType, Int32Type, TimeUnit};
+
+    fn table(result: serde_json::Value) -> ResultTable {
+        serde_json::from_value::<DatabaseQueryResult>(serde_json::json!({ "results": [result] }))
+            .unwrap()
+            .next_table()
+            .unwrap()
+    }
+
+    fn weather() -> ResultTable {
+        table(serde_json::json!({
+            "statement_id": 0,
+            "series": [
+                {
+                    "name": "weather",
+                    "tags": { "location": "berlin" },
+                    "columns": ["time", "temperature", "humidity", "raining"],
+                    "values": [
+                        ["1970-01-01T00:00:01Z", 21.5, 30, false],
+                        ["1970-01-01T00:00:02Z", 22, null, true]
+                    ]
+                },
+                {
+                    "name": "weather",
+                    "tags": { "location": "london" },
+                    "columns": ["time", "temperature", "humidity", "raining"],
+                    "values": [["1970-01-01T00:00:03Z", 18, 80, true]]
+                }
+            ]
+        }))
+    }
+
+    #[test]
+    fn test_record_batch() {
+        let batch = weather().to_record_batch().unwrap();
+        let schema = batch.schema();
+        assert_eq!(
+            schema
+                .fields()
+                .iter()
+                .map(|field| (field.name().as_str(), field.data_type().clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "time",
+                    DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+                ),
+                (
+                    "location",
+                    DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+                ),
+                ("temperature", DataType::Float64),
+                ("humidity", DataType::Int64),
+                ("raining", DataType::Boolean),
+            ]
+        );
+        assert_eq!(batch.num_rows(), 3);
+
+        let time = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(time.value(2), 3_000_000_000);
+
+        let location = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+        let location_values = location.values().as_string::<i32>();
+        assert_eq!(location_values.len(), 2);
+        assert_eq!(
+            location_values.value(location.keys().value(2) as usize),
+            "london"
+        );
+
+        let temperature = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(temperature.values().to_vec(), vec![21.5, 22.0, 18.0]);
+
+        let humidity = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert!(humidity.is_null(1));
+        assert_eq!(humidity.value(2), 80);
+
+        let raining = batch
+            .column(4)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        assert!(!raining.value(0));
+    }
+
+    #[test]
+    fn test_record_batch_with_field_types() {
+        let field_keys = table(serde_json::json!({
+            "statement_id": 0,
+            "series": [{
+                "name": "weather",
+                "columns": ["fieldKey", "fieldType"],
+                "values": [["humidity", "float"], ["raining", "boolean"]]
+            }]
+        }));
+        let field_types = field_types(&field_keys);
+        assert_eq!(field_types["humidity"], DataType::Float64);
+
+        let batch = weather()
+            .to_record_batch_with_field_types(&field_types)
+            .unwrap();
+        let humidity = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(humidity.value(0), 30.0);
+        assert_eq!(batch.column(4).data_type(), &DataType::Boolean);
+    }
+
+    #[test]
+    fn test_record_batch_mixed_types() {
+        let batch = table(serde_json::json!({
+            "statement_id": 0,
+            "series": [{
+                "name": "weather",
+                "columns": ["time", "note"],
+                "values": [[1, "sunny"], [2, true]]
+            }]
+        }))
+        .to_record_batch()
+        .unwrap();
+        let note = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(note.value(1), "true");
+    }
+
+    #[test]
+    fn test_record_batch_type_mismatch() {
+        for data_type in [DataType::Boolean, DataType::Utf8] {
+            let field_types = [("temperature".to_owned(), data_type)]
+                .into_iter()
+                .collect();
+            assert!(weather()
+                .to_record_batch_with_field_types(&field_types)
+                .is_err());
+        }
+    }
+}
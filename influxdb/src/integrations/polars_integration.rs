@@ -0,0 +1,613 @@
+//! Polars Integration for InfluxDB. Converts query results into [`DataFrame`]s and data frames
+//! into points.
+//!
+//! Query results are converted like [`ResultTable`]s into Arrow record batches: the `time`
+//! column is a `Datetime(Nanoseconds, "UTC")` column, the tags of the series are string columns,
+//! and the types of the other columns are inferred from their values. InfluxDB returns floats
+//! without a fractional part as integers, so pass the types of the fields from a
+//! `SHOW FIELD KEYS` query to [`ResultTable::to_data_frame_with_field_types`] or
+//! [`Client::query_df_with_field_types`] to get the exact types, see [`field_types`].
+//! Otherwise, writing a data frame back can turn float fields into integer fields, which
+//! InfluxDB rejects.
+//!
+//! ```rust,no_run
+//! use influxdb::integrations::polars_integration::{field_types, DataFrameWriter};
+//! use influxdb::{Client, ReadQuery};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), influxdb::Error> {
+//! let client = Client::new("http://localhost:8086", "test");
+//! let field_keys = client
+//!     .json_query(ReadQuery::new("SHOW FIELD KEYS FROM weather"))
+//!     .await?
+//!     .next_table()?;
+//! let df = client
+//!     .json_query(ReadQuery::new("SELECT * FROM weather GROUP BY location"))
+//!     .await?
+//!     .next_table()?
+//!     .to_data_frame_with_field_types(&field_types(&field_keys))?;
+//!
+//! let points = DataFrameWriter::new("weather_copy", "time")
+//!     .tags(["location"])
+//!     .to_write_queries(&df)?;
+//! client.query(points).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use polars::prelude::{
+    Column, DataFrame, DataType, Int64Chunked, IntoColumn, NewChunkedArray, PolarsError, Series,
+    TimeUnit, TimeZone,
+};
+use std::collections::HashMap;
+
+use crate::integrations::serde_integration::table::{
+    self, combined_columns, combined_values, ColumnKind, ValueType,
+};
+use crate::integrations::serde_integration::{DynamicSeries, FieldType, FieldValue, ResultTable};
+use crate::{Client, Error, Precision, ReadQuery, Timestamp, Type, WriteQuery};
+
+/// Returns the Polars types of the fields listed by a `SHOW FIELD KEYS` query
+pub fn field_types(show_field_keys: &ResultTable) -> HashMap<String, DataType> {
+    table::field_types(show_field_keys)
+        .into_iter()
+        .map(|(name, field_type)| {
+            let data_type = match field_type {
+                FieldType::Float => DataType::Float64,
+                FieldType::Integer => DataType::Int64,
+                FieldType::Unsigned => DataType::UInt64,
+                FieldType::String => DataType::String,
+                FieldType::Boolean => DataType::Boolean,
+            };
+            (name, data_type)
+        })
+        .collect()
+}
+
+impl ResultTable {
+    /// Converts all series into a single [`DataFrame`], inferring the types of the columns from
+    /// their values, see the [module documentation](crate::integrations::polars_integration)
+    pub fn to_data_frame(&self) -> Result<DataFrame, Error> {
+        self.to_data_frame_with_field_types(&HashMap::new())
+    }
+
+    /// Converts all series into a single [`DataFrame`], using the given types for fields, e.g.
+    /// from [`field_types`]
+    pub fn to_data_frame_with_field_types(
+        &self,
+        field_types: &HashMap<String, DataType>,
+    ) -> Result<DataFrame, Error> {
+        data_frame(&self.series, field_types)
+    }
+}
+
+impl DynamicSeries {
+    /// Converts this series into a [`DataFrame`], see [`ResultTable::to_data_frame`]
+    pub fn to_data_frame(&self) -> Result<DataFrame, Error> {
+        data_frame(std::slice::from_ref(self), &HashMap::new())
+    }
+}
+
+impl Client {
+    /// Sends a [`ReadQuery`] and converts the result of its first statement into a
+    /// [`DataFrame`], see [`ResultTable::to_data_frame`]
+    pub async fn query_df(&self, q: ReadQuery) -> Result<DataFrame, Error> {
+        self.query_df_with_field_types(q, &HashMap::new()).await
+    }
+
+    /// Sends a [`ReadQuery`] and converts the result of its first statement into a
+    /// [`DataFrame`], using the given types for fields, see
+    /// [`ResultTable::to_data_frame_with_field_types`]
+    pub async fn query_df_with_field_types(
+        &self,
+        q: ReadQuery,
+        field_types: &HashMap<String, DataType>,
+    ) -> Result<DataFrame, Error> {
+        self.json_query(q)
+            .await?
+            .next_table()?
+            .to_data_frame_with_field_types(field_types)
+    }
+}
+
+fn data_frame(
+    series: &[DynamicSeries],
+    field_types: &HashMap<String, DataType>,
+) -> Result<DataFrame, Error> {
+    let columns = combined_columns(series)
+        .into_iter()
+        .map(|column| {
+            let name = column.name;
+            let values = combined_values(series, name);
+            let value_type = match column.kind {
+                ColumnKind::Time => {
+                    let values = convert(series, name, |value| {
+                        value
+                            .as_timestamp()
+                            .and_then(|time| time.checked_nanos())
+                            .and_then(|nanos| i64::try_from(nanos).ok())
+                    })?;
+                    return Ok(
+                        Int64Chunked::from_iter_options(name.into(), values.into_iter())
+                            .into_datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC))
+                            .into_column(),
+                    );
+                }
+                ColumnKind::Tag => ValueType::Text,
+                ColumnKind::Field(value_type) => match field_types.get(name) {
+                    Some(DataType::Boolean) => ValueType::Boolean,
+                    Some(DataType::Float64) => ValueType::Float,
+                    Some(DataType::Int64) => ValueType::SignedInteger,
+                    Some(DataType::UInt64) => ValueType::UnsignedInteger,
+                    // Only the values of inferred string columns are stringified, the values of
+                    // string fields in `field_types` must be strings
+                    Some(DataType::String) => {
+                        let values =
+                            convert(series, name, |value| value.as_str().map(str::to_owned))?;
+                        return Ok(Column::new(name.into(), values));
+                    }
+                    Some(data_type) => {
+                        return Err(Error::DeserializationError {
+                            error: format!("unsupported type {data_type} for column {name}"),
+                        })
+                    }
+                    None => value_type,
+                },
+            };
+            Ok(match value_type {
+                ValueType::Null => Column::full_null(name.into(), values.count(), &DataType::Null),
+                ValueType::Boolean => {
+                    Column::new(name.into(), convert(series, name, FieldValue::as_bool)?)
+                }
+                ValueType::Float => {
+                    Column::new(name.into(), convert(series, name, FieldValue::as_f64)?)
+                }
+                ValueType::SignedInteger => {
+                    Column::new(name.into(), convert(series, name, FieldValue::as_i64)?)
+                }
+                ValueType::UnsignedInteger => {
+                    Column::new(name.into(), convert(series, name, FieldValue::as_u64)?)
+                }
+                ValueType::Text => Column::new(
+                    name.into(),
+                    values
+                        .map(|value| match value.as_ref() {
+                            FieldValue::Null => None,
+                            value => Some(value.to_string()),
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    DataFrame::new(columns).map_err(deserialization_error)
+}
+
+/// Converts every non-null value of a column with `convert`, failing if a value does not fit
+fn convert<T>(
+    series: &[DynamicSeries],
+    name: &str,
+    convert: impl Fn(&FieldValue) -> Option<T>,
+) -> Result<Vec<Option<T>>, Error> {
+    combined_values(series, name)
+        .map(|value| match value.as_ref() {
+            FieldValue::Null => Ok(None),
+            value => convert(value)
+                .map(Some)
+                .ok_or_else(|| Error::DeserializationError {
+                    error: format!("could not convert {value:?} in column {name}"),
+                }),
+        })
+        .collect()
+}
+
+fn deserialization_error(err: PolarsError) -> Error {
+    Error::DeserializationError {
+        error: format!("could not build data frame: {err}"),
+    }
+}
+
+/// Converts the rows of a [`DataFrame`] into points, see the
+/// [module documentation](crate::integrations::polars_integration)
+#[derive(Debug, Clone)]
+pub struct DataFrameWriter {
+    measurement: String,
+    time_column: String,
+    precision: Precision,
+    tag_columns: Vec<String>,
+    field_columns: Option<Vec<String>>,
+}
+
+impl DataFrameWriter {
+    /// Creates a new [`DataFrameWriter`] writing to `measurement`, taking the timestamp of every
+    /// point from `time_column`
+    pub fn new<M, T>(measurement: M, time_column: T) -> Self
+    where
+        M: Into<String>,
+        T: Into<String>,
+    {
+        DataFrameWriter {
+            measurement: measurement.into(),
+            time_column: time_column.into(),
+            precision: Precision::Nanoseconds,
+            tag_columns: Vec::new(),
+            field_columns: None,
+        }
+    }
+
+    /// Writes these columns as tags
+    #[must_use]
+    pub fn tags<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tag_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Writes these columns as fields. By default, all columns except the time and tag columns
+    /// are written as fields.
+    #[must_use]
+    pub fn fields<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.field_columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The precision of integer time columns, nanoseconds by default. The precision of datetime
+    /// columns is taken from their type.
+    #[must_use]
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Converts every row of `df` into a point. Null tags and fields are skipped, as are rows
+    /// whose fields are all null.
+    ///
+    /// The returned points can be written in a single request with
+    /// [`Client::query`](crate::Client::query).
+    pub fn to_write_queries(&self, df: &DataFrame) -> Result<Vec<WriteQuery>, Error> {
+        let times = self.times(column(df, &self.time_column)?)?;
+        let tags = self
+            .tag_columns
+            .iter()
+            .map(|name| {
+                let series = column(df, name)?
+                    .as_materialized_series()
+                    .cast(&DataType::String)
+                    .map_err(|err| column_error(name, err))?;
+                let values = series
+                    .str()
+                    .map_err(|err| column_error(name, err))?
+                    .iter()
+                    .map(|value| value.map(Type::from))
+                    .collect();
+                Ok((name.as_str(), values))
+            })
+            .collect::<Result<Vec<(&str, Vec<Option<Type>>)>, Error>>()?;
+        let fields = match &self.field_columns {
+            Some(columns) => columns.iter().map(String::as_str).collect::<Vec<_>>(),
+            None => df
+                .get_column_names()
+                .into_iter()
+                .map(|name| name.as_str())
+                .filter(|name| {
+                    *name != self.time_column && !self.tag_columns.iter().any(|tag| tag == name)
+                })
+                .collect(),
+        };
+        let fields = fields
+            .into_iter()
+            .map(|name| Ok((name, field_values(name, column(df, name)?)?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut queries = Vec::with_capacity(times.len());
+        for (row, time) in times.into_iter().enumerate() {
+            if fields.iter().all(|(_, values)| values[row].is_none()) {
+                continue;
+            }
+            let mut query = WriteQuery::new(time, self.measurement.as_str());
+            for (name, values) in &tags {
+                query = query.add_tag(*name, values[row].clone());
+            }
+            for (name, values) in &fields {
+                query = query.add_field(*name, values[row].clone());
+            }
+            queries.push(query);
+        }
+        Ok(queries)
+    }
+
+    fn times(&self, time: &Column) -> Result<Vec<Timestamp>, Error> {
+        let name = &self.time_column;
+        let (series, precision) = match time.dtype() {
+            DataType::Datetime(unit, _) => {
+                let precision = match unit {
+                    TimeUnit::Nanoseconds => Precision::Nanoseconds,
+                    TimeUnit::Microseconds => Precision::Microseconds,
+                    TimeUnit::Milliseconds => Precision::Milliseconds,
+                };
+                (
+                    time.as_materialized_series()
+                        .to_physical_repr()
+                        .into_owned(),
+                    precision,
+                )
+            }
+            _ => (
+                time.as_materialized_series()
+                    .cast(&DataType::Int64)
+                    .map_err(|err| column_error(name, err))?,
+                self.precision,
+            ),
+        };
+        series
+            .i64()
+            .map_err(|err| column_error(name, err))?
+            .iter()
+            .map(|value| {
+                let value = value.ok_or_else(|| Error::InvalidQueryError {
+                    error: format!("time column {name} contains nulls"),
+                })?;
+                let value = u128::try_from(value).map_err(|_| Error::InvalidQueryError {
+                    error: format!("time column {name} contains times before the unix epoch"),
+                })?;
+                Ok(Timestamp::from_precision(value, precision))
+            })
+            .collect()
+    }
+}
+
+fn column<'a>(df: &'a DataFrame, name: &str) -> Result<&'a Column, Error> {
+    df.column(name).map_err(|err| column_error(name, err))
+}
+
+fn column_error(name: &str, err: PolarsError) -> Error {
+    Error::InvalidQueryError {
+        error: format!("invalid column {name}: {err}"),
+    }
+}
+
+fn field_values(name: &str, column: &Column) -> Result<Vec<Option<Type>>, Error> {
+    let series = column.as_materialized_series();
+    let cast = |data_type: &DataType| -> Result<Series, Error> {
+        series
+            .cast(data_type)
+            .map_err(|err| column_error(name, err))
+    };
+    let values = match series.dtype() {
+        DataType::Boolean => series
+            .bool()
+            .map_err(|err| column_error(name, err))?
+            .iter()
+            .map(|value| value.map(Type::from))
+            .collect(),
+        DataType::Float32 | DataType::Float64 => cast(&DataType::Float64)?
+            .f64()
+            .map_err(|err| column_error(name, err))?
+            .iter()
+            .map(|value| value.map(Type::from))
+            .collect(),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+            cast(&DataType::Int64)?
+                .i64()
+                .map_err(|err| column_error(name, err))?
+                .iter()
+                .map(|value| value.map(Type::from))
+                .collect()
+        }
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            cast(&DataType::UInt64)?
+                .u64()
+                .map_err(|err| column_error(name, err))?
+                .iter()
+                .map(|value| value.map(Type::from))
+                .collect()
+        }
+        _ => cast(&DataType::String)?
+            .str()
+            .map_err(|err| column_error(name, err))?
+            .iter()
+            .map(|value| value.map(Type::from))
+            .collect(),
+    };
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field_types, DataFrameWriter};
+    use crate::integrations::serde_integration::{DatabaseQueryResult, ResultTable};
+    use crate::{Precision, Query};
+    use polars::prelude::{Column, DataFrame, DataType, TimeUnit, TimeZone};
+
+    #[test]
+    fn test_result_table_to_data_frame() {
+        let df = serde_json::from_value::<DatabaseQueryResult>(serde_json::json!({
+            "results": [{
+                "statement_id": 0,
+                "series": [
+                    {
+                        "name": "weather",
+                        "tags": { "location": "berlin" },
+                        "columns": ["time", "temperature", "humidity"],
+                        "values": [["1970-01-01T00:00:01Z", 21.5, 30], ["1970-01-01T00:00:02Z", 22, null]]
+                    },
+                    {
+                        "name": "weather",
+                        "tags": { "location": "london" },
+                        "columns": ["time", "temperature", "humidity"],
+                        "values": [["1970-01-01T00:00:03Z", 18, 80]]
+                    }
+                ]
+            }]
+        }))
+        .unwrap()
+        .next_table()
+        .unwrap()
+        .to_data_frame()
+        .unwrap();
+
+        assert_eq!(df.height(), 3);
+        assert_eq!(
+            df.dtypes(),
+            vec![
+                DataType::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+                DataType::String,
+                DataType::Float64,
+                DataType::Int64,
+            ]
+        );
+        assert_eq!(
+            df.column("location")
+                .unwrap()
+                .str()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some("berlin"), Some("berlin"), Some("london")]
+        );
+        assert_eq!(
+            df.column("humidity")
+                .unwrap()
+                .i64()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(30), None, Some(80)]
+        );
+    }
+
+    fn table(result: serde_json::Value) -> ResultTable {
+        serde_json::from_value::<DatabaseQueryResult>(serde_json::json!({ "results": [result] }))
+            .unwrap()
+            .next_table()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_data_frame_with_field_types() {
+        let field_keys = table(serde_json::json!({
+            "statement_id": 0,
+            "series": [{
+                "name": "weather",
+                "columns": ["fieldKey", "fieldType"],
+                "values": [["temperature", "float"], ["raining", "boolean"]]
+            }]
+        }));
+        let field_types = field_types(&field_keys);
+        assert_eq!(field_types["temperature"], DataType::Float64);
+
+        let weather = table(serde_json::json!({
+            "statement_id": 0,
+            "series": [{
+                "name": "weather",
+                "columns": ["time", "temperature", "raining"],
+                "values": [["1970-01-01T00:00:01Z", 21, false], ["1970-01-01T00:00:02Z", 22, true]]
+            }]
+        }));
+        assert_eq!(
+            weather.to_data_frame().unwrap().dtypes()[1],
+            DataType::Int64
+        );
+
+        let df = weather
+            .to_data_frame_with_field_types(&field_types)
+            .unwrap();
+        assert_eq!(
+            df.dtypes(),
+            vec![
+                DataType::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+                DataType::Float64,
+                DataType::Boolean,
+            ]
+        );
+        let queries = DataFrameWriter::new("weather", "time")
+            .to_write_queries(&df)
+            .unwrap();
+        assert_eq!(
+            queries[0].build().unwrap().get(),
+            "weather temperature=21,raining=false 1000000000"
+        );
+
+        for data_type in [DataType::Boolean, DataType::String] {
+            let field_types = [("temperature".to_owned(), data_type)]
+                .into_iter()
+                .collect();
+            assert!(weather
+                .to_data_frame_with_field_types(&field_types)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_data_frame_to_write_queries() {
+        let df = DataFrame::new(vec![
+            Column::new("time".into(), [1_i64, 2]),
+            Column::new("location".into(), ["berlin", "london"]),
+            Column::new("temperature".into(), [Some(21.5), None]),
+            Column::new("humidity".into(), [30_i32, 80]),
+            Column::new("ignored".into(), [true, false]),
+        ])
+        .unwrap();
+
+        let queries = DataFrameWriter::new("weather", "time")
+            .tags(["location"])
+            .fields(["temperature", "humidity"])
+            .precision(Precision::Seconds)
+            .to_write_queries(&df)
+            .unwrap();
+        assert_eq!(
+            queries
+                .iter()
+                .map(|query| query.build().unwrap().get())
+                .collect::<Vec<_>>(),
+            vec![
+                "weather,location=berlin temperature=21.5,humidity=30i 1",
+                "weather,location=london humidity=80i 2",
+            ]
+        );
+
+        let queries = DataFrameWriter::new("weather", "time")
+            .tags(["location"])
+            .to_write_queries(&df)
+            .unwrap();
+        assert_eq!(
+            queries[1].build().unwrap().get(),
+            "weather,location=london humidity=80i,ignored=false 2"
+        );
+
+        let queries = DataFrameWriter::new("weather", "time")
+            .tags(["location"])
+            .fields(["temperature"])
+            .to_write_queries(&df)
+            .unwrap();
+        assert_eq!(queries.len(), 1);
+    }
+
+    #[test]
+    fn test_data_frame_with_datetime_column() {
+        let time = Column::new("time".into(), [1_500_i64])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let df = DataFrame::new(vec![time, Column::new("value".into(), [1.5])]).unwrap();
+
+        let queries = DataFrameWriter::new("m", "time")
+            .to_write_queries(&df)
+            .unwrap();
+        assert_eq!(queries[0].build().unwrap().get(), "m value=1.5 1500");
+    }
+
+    #[test]
+    fn test_data_frame_missing_column() {
+        let df = DataFrame::new(vec![Column::new("value".into(), [1.5])]).unwrap();
+        assert!(DataFrameWriter::new("m", "time")
+            .to_write_queries(&df)
+            .is_err());
+    }
+}
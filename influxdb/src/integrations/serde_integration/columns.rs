@@ -0,0 +1,227 @@
+//! Columnar deserialization of series.
+//!
+//! Instead of one value per row, the values of a series are deserialized into a single value
+//! holding one collection per column, see
+//! [`ColumnarSeries`](crate::integrations::serde_integration::ColumnarSeries). The columns are
+//! read straight from the parsed rows without buffering them.
+
+use serde::de::{
+    DeserializeSeed, Deserializer, Error as _, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::Deserialize;
+use serde_json::{Error, Value};
+use std::slice;
+
+/// Returns the name, tags and columnar values of a series
+pub(super) fn deserialize_series<'de, TAG, T>(series: &'de Value) -> Result<(String, TAG, T), Error>
+where
+    TAG: Deserialize<'de>,
+    T: Deserialize<'de>,
+{
+    let field = |key| series.get(key).unwrap_or(&Value::Null);
+    let name = String::deserialize(field("name"))?;
+    let tags = TAG::deserialize(field("tags"))?;
+    let columns = Vec::<String>::deserialize(field("columns"))?;
+    let rows = match field("values") {
+        Value::Null => &[],
+        Value::Array(rows) => rows.as_slice(),
+        _ => return Err(Error::custom("series values must be an array of rows")),
+    };
+    let values = T::deserialize(SeriesColumns {
+        columns: &columns,
+        rows,
+    })?;
+    Ok((name, tags, values))
+}
+
+// Deserializer for all columns of a series, which is deserialized as a map from the column
+// names to their values. Sequences are deserialized from the only column besides `time`.
+struct SeriesColumns<'c, 'de> {
+    columns: &'c [String],
+    rows: &'de [Value],
+}
+
+impl<'de> Deserializer<'de> for SeriesColumns<'_, 'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(ColumnsMapAccess {
+            columns: self.columns.iter().enumerate(),
+            rows: self.rows,
+            column: 0,
+        })
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let column = match self.columns {
+            [_] => 0,
+            [time, _] if time == "time" => 1,
+            columns => {
+                return Err(Error::custom(format!(
+                    "expected a single column besides time, got {columns:?}"
+                )))
+            }
+        };
+        Column {
+            rows: self.rows,
+            column,
+        }
+        .deserialize_seq(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct ColumnsMapAccess<'c, 'de> {
+    columns: std::iter::Enumerate<slice::Iter<'c, String>>,
+    rows: &'de [Value],
+    column: usize,
+}
+
+impl<'de> MapAccess<'de> for ColumnsMapAccess<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.columns.next() {
+            Some((column, name)) => {
+                self.column = column;
+                seed.deserialize(name.as_str().into_deserializer())
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(Column {
+            rows: self.rows,
+            column: self.column,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.columns.len())
+    }
+}
+
+// Deserializer for a single column, which is deserialized as a sequence of the values of that
+// column in all rows
+struct Column<'de> {
+    rows: &'de [Value],
+    column: usize,
+}
+
+impl<'de> Deserializer<'de> for Column<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(ColumnSeqAccess {
+            rows: self.rows.iter(),
+            column: self.column,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct ColumnSeqAccess<'de> {
+    rows: slice::Iter<'de, Value>,
+    column: usize,
+}
+
+impl<'de> SeqAccess<'de> for ColumnSeqAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.rows.next() {
+            Some(row) => {
+                let value = row.get(self.column).unwrap_or(&Value::Null);
+                seed.deserialize(value).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.rows.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::deserialize_series;
+    use serde::de::IgnoredAny;
+    use serde_derive::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Cpu {
+        time: Vec<i64>,
+        usage: Vec<Option<f64>>,
+        host: Vec<String>,
+    }
+
+    fn series() -> serde_json::Value {
+        serde_json::json!({
+            "name": "cpu",
+            "tags": { "region": "eu" },
+            "columns": ["time", "usage", "host"],
+            "values": [[1, 0.5, "a"], [2, null, "b"]]
+        })
+    }
+
+    #[test]
+    fn test_deserialize_struct_of_vecs() {
+        let series = series();
+        let (name, tags, cpu) =
+            deserialize_series::<HashMap<String, String>, Cpu>(&series).unwrap();
+        assert_eq!(name, "cpu");
+        assert_eq!(tags["region"], "eu");
+        assert_eq!(
+            cpu,
+            Cpu {
+                time: vec![1, 2],
+                usage: vec![Some(0.5), None],
+                host: vec!["a".to_string(), "b".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_single_column() {
+        let single = serde_json::json!({
+            "name": "cpu",
+            "columns": ["time", "usage"],
+            "values": [[1, 0.5], [2, 0.25]]
+        });
+        let (_, (), usage) = deserialize_series::<(), Vec<f64>>(&single).unwrap();
+        assert_eq!(usage, vec![0.5, 0.25]);
+
+        let series = series();
+        let err = deserialize_series::<IgnoredAny, Vec<f64>>(&series).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("expected a single column besides time"));
+    }
+
+    #[test]
+    fn test_deserialize_empty_series() {
+        let series = serde_json::json!({ "name": "cpu", "columns": ["time", "usage"] });
+        let (_, (), usage) = deserialize_series::<(), Vec<f64>>(&series).unwrap();
+        assert!(usage.is_empty());
+    }
+}
@@ -1,7 +1,7 @@
 use super::{Series, TaggedSeries};
 use serde::de::{
-    value, Deserialize, DeserializeSeed, Deserializer, Error, IntoDeserializer, MapAccess,
-    SeqAccess, Visitor,
+    value, Deserialize, DeserializeSeed, Deserializer, Error, IgnoredAny, IntoDeserializer,
+    MapAccess, SeqAccess, Visitor,
 };
 use serde_derive::Deserialize;
 use std::fmt;
@@ -23,6 +23,8 @@ where
             Name,
             Columns,
             Values,
+            #[serde(other)]
+            Other,
         }
 
         struct SeriesVisitor<T> {
@@ -78,6 +80,9 @@ where
                                 _inner_type: PhantomData,
                             })?);
                         }
+                        Field::Other => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
                     }
                 }
                 let name = name.ok_or_else(|| Error::missing_field("name"))?;
@@ -116,6 +121,8 @@ where
             Tags,
             Columns,
             Values,
+            #[serde(other)]
+            Other,
         }
 
         struct SeriesVisitor<TAG, T> {
@@ -180,6 +187,9 @@ where
                                 _inner_type: PhantomData,
                             })?);
                         }
+                        Field::Other => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
                     }
                 }
                 let name = name.ok_or_else(|| Error::missing_field("name"))?;
@@ -448,4 +458,32 @@ mod tests {
             },
         );
     }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_deserialize_jiff_time() {
+        use serde_derive::Deserialize;
+
+        #[derive(Deserialize, Debug, PartialEq, Eq)]
+        struct Reading {
+            time: jiff::Timestamp,
+            temperature: i64,
+        }
+
+        let data = r#"
+        {
+            "name": "weather",
+            "columns": ["time", "temperature"],
+            "values": [["1970-01-01T00:00:01.5Z", 82]]
+        }
+        "#;
+        let result = serde_json::from_str::<Series<Reading>>(data).unwrap();
+        assert_eq!(
+            result.values,
+            vec![Reading {
+                time: jiff::Timestamp::from_millisecond(1500).unwrap(),
+                temperature: 82,
+            }]
+        );
+    }
 }
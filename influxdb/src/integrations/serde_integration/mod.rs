@@ -44,13 +44,27 @@
 //! # }
 //! ```
 
+mod columns;
 mod de;
+mod ser;
+mod stream;
+pub mod table;
+pub mod timestamp;
 
-use serde::de::DeserializeOwned;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Deserialize as _;
 use serde_derive::Deserialize;
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::collections::VecDeque;
 
-use crate::client::check_status;
-use crate::{Client, Error, Query, ReadQuery};
+use crate::{Client, Error, Precision, Query};
+
+pub use ser::PointSchema;
+pub use table::{DynamicRow, DynamicSeries, FieldType, FieldValue, ResultTable};
+
+#[cfg(feature = "derive")]
+pub use influxdb_derive::InfluxDbReadable;
 
 #[derive(Deserialize)]
 #[doc(hidden)]
@@ -58,22 +72,63 @@ struct _DatabaseError {
     error: String,
 }
 
-#[derive(Deserialize, Debug)]
-#[doc(hidden)]
+/// The results of all statements of a query, see [`StatementResult`].
+///
+/// This is an iterator over the statement results that haven't been read yet. The
+/// `deserialize_next` and `read_next` functions read the next statement result, and fail if it
+/// is missing or InfluxDB reported an error for it.
+#[derive(Deserialize, Debug, Default)]
 pub struct DatabaseQueryResult {
-    pub results: Vec<serde_json::Value>,
+    #[serde(default)]
+    results: VecDeque<StatementResult>,
 }
 
 impl DatabaseQueryResult {
+    /// Returns the next statement result, or an error if there is none left
+    pub fn next_statement(&mut self) -> Result<StatementResult, Error> {
+        self.results
+            .pop_front()
+            .ok_or_else(|| Error::DeserializationError {
+                error: "no statement results left".to_string(),
+            })
+    }
+
     pub fn deserialize_next<T: 'static>(&mut self) -> Result<Return<T>, Error>
     where
         T: DeserializeOwned + Send,
     {
-        serde_json::from_value::<Return<T>>(self.results.remove(0)).map_err(|err| {
-            Error::DeserializationError {
-                error: format!("could not deserialize: {err}"),
-            }
-        })
+        self.next_statement()?.deserialize()
+    }
+
+    /// Reads all rows of all series of the next statement result into `T`, see
+    /// [`InfluxDbReadable`].
+    pub fn read_next<T: InfluxDbReadable>(&mut self) -> Result<Vec<T>, Error> {
+        self.next_statement()?.read()
+    }
+
+    /// Deserializes the next statement result column by column, see [`ColumnarSeries`]
+    pub fn deserialize_next_columns<T>(&mut self) -> Result<ColumnarReturn<T>, Error>
+    where
+        T: DeserializeOwned + Send,
+    {
+        self.next_statement()?.deserialize_columns()
+    }
+
+    /// Deserializes the next statement result column by column, including the tags of each
+    /// series, see [`ColumnarSeries`]
+    pub fn deserialize_next_columns_tagged<TAG, T>(
+        &mut self,
+    ) -> Result<TaggedColumnarReturn<TAG, T>, Error>
+    where
+        TAG: DeserializeOwned + Send,
+        T: DeserializeOwned + Send,
+    {
+        self.next_statement()?.deserialize_columns_tagged()
+    }
+
+    /// Reads the next statement result into a [`ResultTable`]
+    pub fn next_table(&mut self) -> Result<ResultTable, Error> {
+        self.next_statement()?.table()
     }
 
     pub fn deserialize_next_tagged<TAG, T: 'static>(
@@ -83,12 +138,302 @@ impl DatabaseQueryResult {
         TAG: DeserializeOwned + Send,
         T: DeserializeOwned + Send,
     {
-        serde_json::from_value::<TaggedReturn<TAG, T>>(self.results.remove(0)).map_err(|err| {
-            Error::DeserializationError {
-                error: format!("could not deserialize: {err}"),
-            }
+        self.next_statement()?.deserialize_tagged()
+    }
+
+    /// Deserializes the results of all remaining statements at once, one tuple element per
+    /// statement.
+    ///
+    /// Fails if the number of remaining statement results doesn't match the length of the tuple.
+    ///
+    /// ```rust,no_run
+    /// use influxdb::integrations::serde_integration::{Return, TaggedReturn};
+    /// use influxdb::{Client, ReadQuery};
+    /// use serde_derive::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Temperature {
+    ///     temperature: i32,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Location {
+    ///     location: String,
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), influxdb::Error> {
+    /// let client = Client::new("http://localhost:8086", "test");
+    /// let query = ReadQuery::new("SELECT temperature FROM weather")
+    ///     .add_query("SELECT count(temperature) FROM weather GROUP BY location");
+    /// let (temperatures, counts): (Return<Temperature>, TaggedReturn<Location, serde_json::Value>) =
+    ///     client.json_query(query).await?.deserialize_all()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deserialize_all<T: FromStatementResults>(&mut self) -> Result<T, Error> {
+        if self.results.len() != T::LEN {
+            return Err(Error::DeserializationError {
+                error: format!(
+                    "expected {} statement results, got {}",
+                    T::LEN,
+                    self.results.len()
+                ),
+            });
+        }
+        T::from_statement_results(self)
+    }
+
+    /// Sets the precision of integer times, see [`ReadQuery::with_epoch`](crate::ReadQuery::with_epoch)
+    fn set_epoch(&mut self, epoch: Option<Precision>) {
+        for result in &mut self.results {
+            result.epoch = epoch;
+        }
+    }
+}
+
+/// Types that can be read from the result of a single statement, see
+/// [`DatabaseQueryResult::deserialize_all`]
+pub trait FromStatementResult: Sized {
+    fn from_statement_result(result: &StatementResult) -> Result<Self, Error>;
+}
+
+impl<T> FromStatementResult for Return<T>
+where
+    T: DeserializeOwned + Send,
+{
+    fn from_statement_result(result: &StatementResult) -> Result<Self, Error> {
+        result.deserialize()
+    }
+}
+
+impl<TAG, T> FromStatementResult for TaggedReturn<TAG, T>
+where
+    TAG: DeserializeOwned + Send,
+    T: DeserializeOwned + Send,
+{
+    fn from_statement_result(result: &StatementResult) -> Result<Self, Error> {
+        result.deserialize_tagged()
+    }
+}
+
+impl<T> FromStatementResult for ColumnarReturn<T>
+where
+    T: DeserializeOwned + Send,
+{
+    fn from_statement_result(result: &StatementResult) -> Result<Self, Error> {
+        result.deserialize_columns()
+    }
+}
+
+impl<TAG, T> FromStatementResult for TaggedColumnarReturn<TAG, T>
+where
+    TAG: DeserializeOwned + Send,
+    T: DeserializeOwned + Send,
+{
+    fn from_statement_result(result: &StatementResult) -> Result<Self, Error> {
+        result.deserialize_columns_tagged()
+    }
+}
+
+impl FromStatementResult for ResultTable {
+    fn from_statement_result(result: &StatementResult) -> Result<Self, Error> {
+        result.table()
+    }
+}
+
+impl<T: InfluxDbReadable> FromStatementResult for Vec<T> {
+    fn from_statement_result(result: &StatementResult) -> Result<Self, Error> {
+        result.read()
+    }
+}
+
+/// Tuples of [`FromStatementResult`]s, read from the results of consecutive statements
+pub trait FromStatementResults: Sized {
+    /// The number of statement results
+    const LEN: usize;
+
+    fn from_statement_results(results: &mut DatabaseQueryResult) -> Result<Self, Error>;
+}
+
+macro_rules! from_statement_results_impl {
+        ( $len:expr => $( $typ:ident ),+ ) => (
+                impl<$( $typ: FromStatementResult ),+> FromStatementResults for ($( $typ, )+) {
+                    const LEN: usize = $len;
+
+                    fn from_statement_results(results: &mut DatabaseQueryResult) -> Result<Self, Error> {
+                        Ok(($( $typ::from_statement_result(&results.next_statement()?)?, )+))
+                    }
+                }
+        )
+}
+from_statement_results_impl! {1 => A}
+from_statement_results_impl! {2 => A, B}
+from_statement_results_impl! {3 => A, B, C}
+from_statement_results_impl! {4 => A, B, C, D}
+from_statement_results_impl! {5 => A, B, C, D, E}
+from_statement_results_impl! {6 => A, B, C, D, E, F}
+from_statement_results_impl! {7 => A, B, C, D, E, F, G}
+from_statement_results_impl! {8 => A, B, C, D, E, F, G, H}
+
+impl Iterator for DatabaseQueryResult {
+    type Item = StatementResult;
+
+    fn next(&mut self) -> Option<StatementResult> {
+        self.results.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.results.len(), Some(self.results.len()))
+    }
+}
+
+impl ExactSizeIterator for DatabaseQueryResult {}
+
+/// The result of a single statement of a query
+#[derive(Deserialize, Debug, Clone)]
+pub struct StatementResult {
+    /// The index of the statement in the query
+    #[serde(default)]
+    pub statement_id: usize,
+    /// The error InfluxDB reported for this statement, if any
+    pub error: Option<String>,
+    /// Informational messages and warnings InfluxDB reported for this statement
+    #[serde(default)]
+    pub messages: Vec<Message>,
+    /// Whether this is only a part of the result, which is continued in the next result
+    #[serde(default)]
+    pub partial: bool,
+    #[serde(default)]
+    series: Value,
+    #[serde(skip)]
+    epoch: Option<Precision>,
+}
+
+impl StatementResult {
+    /// Returns `Error::DatabaseError` if InfluxDB reported an error for this statement
+    pub fn check(&self) -> Result<(), Error> {
+        match &self.error {
+            Some(error) => Err(Error::DatabaseError {
+                error: error.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Deserializes the series of this statement result
+    pub fn deserialize<T>(&self) -> Result<Return<T>, Error>
+    where
+        T: DeserializeOwned + Send,
+    {
+        Ok(Return {
+            series: self.series()?,
         })
     }
+
+    /// Deserializes the series of this statement result, including their tags
+    pub fn deserialize_tagged<TAG, T>(&self) -> Result<TaggedReturn<TAG, T>, Error>
+    where
+        TAG: DeserializeOwned + Send,
+        T: DeserializeOwned + Send,
+    {
+        Ok(TaggedReturn {
+            series: self.series()?,
+        })
+    }
+
+    /// Deserializes the series of this statement result column by column, see
+    /// [`ColumnarSeries`]
+    pub fn deserialize_columns<T>(&self) -> Result<ColumnarReturn<T>, Error>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let series = self
+            .columnar_series::<IgnoredAny, T>()?
+            .into_iter()
+            .map(|(name, _, values)| ColumnarSeries { name, values })
+            .collect();
+        Ok(ColumnarReturn { series })
+    }
+
+    /// Deserializes the series of this statement result column by column, including their
+    /// tags, see [`ColumnarSeries`]
+    pub fn deserialize_columns_tagged<TAG, T>(&self) -> Result<TaggedColumnarReturn<TAG, T>, Error>
+    where
+        TAG: DeserializeOwned + Send,
+        T: DeserializeOwned + Send,
+    {
+        let series = self
+            .columnar_series::<TAG, T>()?
+            .into_iter()
+            .map(|(name, tags, values)| TaggedColumnarSeries { name, tags, values })
+            .collect();
+        Ok(TaggedColumnarReturn { series })
+    }
+
+    /// Reads all rows of all series of this statement result into `T`, see
+    /// [`InfluxDbReadable`].
+    pub fn read<T: InfluxDbReadable>(&self) -> Result<Vec<T>, Error> {
+        let series = self.series::<RowSeries>()?;
+        let mut rows = Vec::new();
+        for series in &series {
+            for values in &series.values {
+                rows.push(T::from_row(&ReadRow {
+                    name: &series.name,
+                    tags: series.tags.as_ref(),
+                    columns: &series.columns,
+                    values,
+                    prefix: Cow::Borrowed(""),
+                })?);
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Reads this statement result into a [`ResultTable`]
+    pub fn table(&self) -> Result<ResultTable, Error> {
+        ResultTable::from_statement_result(self)
+    }
+
+    fn columnar_series<TAG, T>(&self) -> Result<Vec<(String, TAG, T)>, Error>
+    where
+        TAG: DeserializeOwned,
+        T: DeserializeOwned,
+    {
+        self.check()?;
+        let series = match &self.series {
+            Value::Null => return Ok(Vec::new()),
+            Value::Array(series) => series,
+            _ => {
+                return Err(Error::DeserializationError {
+                    error: "could not deserialize: series must be an array".to_string(),
+                })
+            }
+        };
+        series
+            .iter()
+            .map(columns::deserialize_series)
+            .collect::<Result<_, _>>()
+            .map_err(|err| Error::DeserializationError {
+                error: format!("could not deserialize: {err}"),
+            })
+    }
+
+    fn series<S: DeserializeOwned>(&self) -> Result<Vec<S>, Error> {
+        self.check()?;
+        Option::<Vec<S>>::deserialize(&self.series)
+            .map(Option::unwrap_or_default)
+            .map_err(|err| Error::DeserializationError {
+                error: format!("could not deserialize: {err}"),
+            })
+    }
+}
+
+/// A message InfluxDB reported for a statement, e.g. a deprecation warning
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub level: String,
+    pub text: String,
 }
 
 #[derive(Deserialize, Debug)]
@@ -120,55 +465,370 @@ pub struct TaggedSeries<TAG, T> {
     pub values: Vec<T>,
 }
 
-impl Client {
-    pub async fn json_query(&self, q: ReadQuery) -> Result<DatabaseQueryResult, Error> {
-        let query = q.build().map_err(|err| Error::InvalidQueryError {
-            error: err.to_string(),
-        })?;
-
-        let read_query = query.get();
-        let read_query_lower = read_query.to_lowercase();
-
-        if !read_query_lower.contains("select") && !read_query_lower.contains("show") {
-            let error = Error::InvalidQueryError {
-                error: "Only SELECT and SHOW queries supported with JSON deserialization".into(),
-            };
-            return Err(error);
+/// The series of a statement result deserialized column by column, see [`ColumnarSeries`]
+#[derive(Debug)]
+pub struct ColumnarReturn<T> {
+    pub series: Vec<ColumnarSeries<T>>,
+}
+
+/// A series deserialized column by column.
+///
+/// Instead of one `T` per row, `values` holds all rows, e.g. a struct with a `Vec` per
+/// column. Every field of the struct is read from the column with the same name. A single
+/// `Vec` is read from the only column besides `time`.
+///
+/// ```rust
+/// use influxdb::integrations::serde_integration::DatabaseQueryResult;
+/// use serde_derive::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Cpu {
+///     time: Vec<String>,
+///     usage: Vec<f64>,
+///     host: Vec<String>,
+/// }
+///
+/// let mut result: DatabaseQueryResult = serde_json::from_str(
+///     r#"{ "results": [{ "statement_id": 0, "series": [{
+///         "name": "cpu",
+///         "columns": ["time", "host", "usage"],
+///         "values": [["1970-01-01T00:00:00Z", "a", 0.5], ["1970-01-01T00:00:01Z", "b", 0.25]]
+///     }] }] }"#,
+/// )
+/// .unwrap();
+/// let cpu = result.deserialize_next_columns::<Cpu>().unwrap();
+/// assert_eq!(cpu.series[0].values.usage, vec![0.5, 0.25]);
+/// assert_eq!(cpu.series[0].values.host, vec!["a", "b"]);
+/// ```
+#[derive(Debug)]
+pub struct ColumnarSeries<T> {
+    pub name: String,
+    pub values: T,
+}
+
+/// The series of a statement result deserialized column by column, including their tags
+#[derive(Debug)]
+pub struct TaggedColumnarReturn<TAG, T> {
+    pub series: Vec<TaggedColumnarSeries<TAG, T>>,
+}
+
+/// A series deserialized column by column, including its tags, see [`ColumnarSeries`]
+#[derive(Debug)]
+pub struct TaggedColumnarSeries<TAG, T> {
+    pub name: String,
+    pub tags: TAG,
+    pub values: T,
+}
+
+#[derive(Deserialize)]
+struct RowSeries {
+    name: String,
+    tags: Option<Map<String, Value>>,
+    columns: Vec<String>,
+    values: Vec<Vec<Value>>,
+}
+
+/// Types that can be read from a row of a query result.
+///
+/// This is usually implemented using `#[derive(InfluxDbReadable)]`, which reads the time field
+/// from the `time` column, tags from the series tags or their column, and fields from their
+/// column, honoring the same attributes as `#[derive(InfluxDbWriteable)]`.
+///
+/// ```rust,no_run
+/// # #[cfg(feature = "chrono")] {
+/// use chrono::{DateTime, Utc};
+/// use influxdb::{Client, InfluxDbReadable, InfluxDbWriteable, ReadQuery};
+///
+/// #[derive(InfluxDbReadable, InfluxDbWriteable)]
+/// struct Weather {
+///     time: DateTime<Utc>,
+///     #[influxdb(tag)]
+///     location: String,
+///     temperature: f64,
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), influxdb::Error> {
+/// let client = Client::new("http://localhost:8086", "test");
+/// let query = ReadQuery::new("SELECT * FROM weather GROUP BY location");
+/// let readings = client.json_query(query).await?.read_next::<Weather>()?;
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+pub trait InfluxDbReadable: Sized {
+    fn from_row(row: &ReadRow<'_>) -> Result<Self, Error>;
+}
+
+/// A single row of a series returned by InfluxDB, see [`InfluxDbReadable`]
+#[derive(Debug)]
+pub struct ReadRow<'a> {
+    name: &'a str,
+    tags: Option<&'a Map<String, Value>>,
+    columns: &'a [String],
+    values: &'a [Value],
+    prefix: Cow<'a, str>,
+}
+
+impl<'a> ReadRow<'a> {
+    /// Returns the name of the series, i.e. the measurement
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Deserializes the name of the series
+    pub fn measurement<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        deserialize_value("measurement", Some(&Value::String(self.name.to_owned())))
+    }
+
+    /// Deserializes the `time` column
+    pub fn time<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        deserialize_value("time", self.column("time"))
+    }
+
+    /// Deserializes the tag `key`, which is either a tag of the series for `GROUP BY` queries,
+    /// or a column otherwise. Missing tags are deserialized from `null`.
+    pub fn tag<T: DeserializeOwned>(&self, key: &str) -> Result<T, Error> {
+        let key = self.prefixed(key);
+        let value = self
+            .tags
+            .and_then(|tags| tags.get(key.as_ref()))
+            .or_else(|| self.column(&key));
+        deserialize_value(&key, value)
+    }
+
+    /// Deserializes the field `key`. Missing fields are deserialized from `null`.
+    pub fn field<T: DeserializeOwned>(&self, key: &str) -> Result<T, Error> {
+        let key = self.prefixed(key);
+        deserialize_value(&key, self.column(&key))
+    }
+
+    /// Returns a view of this row that prepends `prefix` to all tag and field keys
+    pub fn with_prefix(&self, prefix: &str) -> ReadRow<'a> {
+        ReadRow {
+            name: self.name,
+            tags: self.tags,
+            columns: self.columns,
+            values: self.values,
+            prefix: Cow::Owned(format!("{}{prefix}", self.prefix)),
         }
+    }
 
-        let url = &format!("{}/query", &self.url);
-        let mut parameters = self.parameters.as_ref().clone();
-        parameters.insert("q", read_query);
-        let mut request_builder = self.client.get(url);
-        if let Some(ref token) = self.token {
-            request_builder = request_builder.header("Authorization", format!("Token {token}"))
+    fn prefixed<'k>(&self, key: &'k str) -> Cow<'k, str> {
+        match self.prefix.is_empty() {
+            true => Cow::Borrowed(key),
+            false => Cow::Owned(format!("{}{key}", self.prefix)),
         }
-        let request_builder = request_builder.query(&parameters);
+    }
 
-        let res = request_builder
-            .send()
-            .await
-            .map_err(|err| Error::ConnectionError {
-                error: err.to_string(),
-            })?;
-        check_status(&res)?;
+    fn column(&self, key: &str) -> Option<&'a Value> {
+        self.columns
+            .iter()
+            .position(|column| column == key)
+            .and_then(|idx| self.values.get(idx))
+    }
+}
 
-        let body = res.bytes();
+fn deserialize_value<T: DeserializeOwned>(key: &str, value: Option<&Value>) -> Result<T, Error> {
+    T::deserialize(value.unwrap_or(&Value::Null)).map_err(|err| Error::DeserializationError {
+        error: format!("could not deserialize {key}: {err}"),
+    })
+}
+
+impl Client {
+    /// Sends a query and parses the results of its statements, see [`DatabaseQueryResult`].
+    ///
+    /// Any query can be sent, including `CREATE`, `DROP` and `SELECT ... INTO` statements and
+    /// [`WriteQuery`](crate::WriteQuery)s, which return no statement results. Errors of the
+    /// request and errors InfluxDB reported for the whole query are returned right away, while
+    /// errors of single statements are returned when reading their results, see
+    /// [`StatementResult::check`].
+    ///
+    /// Only the parsed results are kept, use [`Client::query`] to get the raw response body.
+    pub async fn json_query<Q: Query>(&self, q: Q) -> Result<DatabaseQueryResult, Error> {
+        let body = self.send(&q).await?;
+        parse_response(&body, q.get_epoch())
+    }
+}
 
-        let body = body.await.map_err(|err| Error::ProtocolError {
-            error: err.to_string(),
-        })?;
+/// Parses a response body of InfluxDB, returning an error if InfluxDB reported an error for the
+/// whole query. Empty bodies, like the responses to writes, contain no statement results.
+pub(crate) fn parse_response(
+    body: &str,
+    epoch: Option<Precision>,
+) -> Result<DatabaseQueryResult, Error> {
+    if body.trim().is_empty() {
+        return Ok(DatabaseQueryResult::default());
+    }
 
-        // Try parsing InfluxDBs { "error": "error message here" }
-        if let Ok(error) = serde_json::from_slice::<_DatabaseError>(&body) {
-            return Err(Error::DatabaseError { error: error.error });
+    // Try parsing InfluxDBs { "error": "error message here" }
+    if let Ok(error) = serde_json::from_str::<_DatabaseError>(body) {
+        return Err(Error::DatabaseError { error: error.error });
+    }
+
+    let mut result = serde_json::from_str::<DatabaseQueryResult>(body).map_err(|err| {
+        Error::DeserializationError {
+            error: format!("serde error: {err}"),
         }
+    })?;
+    result.set_epoch(epoch);
+    Ok(result)
+}
 
-        // Json has another structure, let's try actually parsing it to the type we're deserializing
-        serde_json::from_slice::<DatabaseQueryResult>(&body).map_err(|err| {
-            Error::DeserializationError {
-                error: format!("serde error: {err}"),
+#[cfg(test)]
+mod tests {
+    use super::{parse_response, DatabaseQueryResult, Message, Return, TaggedReturn};
+    use crate::Error;
+    use serde_derive::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Weather {
+        temperature: i32,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Location {
+        location: String,
+    }
+
+    fn result() -> DatabaseQueryResult {
+        serde_json::from_value(serde_json::json!({
+            "results": [
+                {
+                    "statement_id": 0,
+                    "messages": [{ "level": "warning", "text": "deprecated" }],
+                    "series": [{
+                        "name": "weather",
+                        "columns": ["temperature"],
+                        "values": [[21]]
+                    }]
+                },
+                { "statement_id": 1, "error": "measurement not found" },
+                { "statement_id": 2, "partial": true }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_statement_results() {
+        let mut result = result();
+        assert_eq!(result.len(), 3);
+
+        let statement = result.next().unwrap();
+        assert_eq!(statement.statement_id, 0);
+        assert_eq!(
+            statement.messages,
+            vec![Message {
+                level: "warning".to_string(),
+                text: "deprecated".to_string()
+            }]
+        );
+        assert!(!statement.partial);
+        assert_eq!(
+            statement.deserialize::<Weather>().unwrap().series[0].values,
+            vec![Weather { temperature: 21 }]
+        );
+
+        let statement = result.next().unwrap();
+        assert_eq!(statement.statement_id, 1);
+        assert!(matches!(
+            statement.deserialize::<Weather>(),
+            Err(Error::DatabaseError { error }) if error == "measurement not found"
+        ));
+
+        let statement = result.next().unwrap();
+        assert!(statement.partial);
+        assert!(statement
+            .deserialize::<Weather>()
+            .unwrap()
+            .series
+            .is_empty());
+
+        assert!(result.next().is_none());
+    }
+
+    #[test]
+    fn test_deserialize_all() {
+        let mut result: DatabaseQueryResult = serde_json::from_value(serde_json::json!({
+            "results": [
+                {
+                    "statement_id": 0,
+                    "series": [{
+                        "name": "weather",
+                        "columns": ["temperature"],
+                        "values": [[21]]
+                    }]
+                },
+                {
+                    "statement_id": 1,
+                    "series": [{
+                        "name": "weather",
+                        "tags": { "location": "berlin" },
+                        "columns": ["temperature"],
+                        "values": [[22]]
+                    }]
+                }
+            ]
+        }))
+        .unwrap();
+
+        let (untagged, tagged): (Return<Weather>, TaggedReturn<Location, Weather>) =
+            result.deserialize_all().unwrap();
+        assert_eq!(untagged.series[0].values, vec![Weather { temperature: 21 }]);
+        assert_eq!(
+            tagged.series[0].tags,
+            Location {
+                location: "berlin".to_string()
             }
-        })
+        );
+        assert_eq!(tagged.series[0].values, vec![Weather { temperature: 22 }]);
+    }
+
+    #[test]
+    fn test_deserialize_all_checks_statement_count() {
+        assert!(matches!(
+            result().deserialize_all::<(Return<Weather>, Return<Weather>)>(),
+            Err(Error::DeserializationError { error }) if error == "expected 2 statement results, got 3"
+        ));
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response("", None).unwrap().len(), 0);
+        assert_eq!(
+            parse_response(r#"{"error":"error parsing query"}"#, None).unwrap_err(),
+            Error::DatabaseError {
+                error: "error parsing query".to_string()
+            }
+        );
+
+        let mut result = parse_response(
+            r#"{"results":[{"statement_id":0},{"statement_id":1,"error":"database not found: db"}]}"#,
+            None,
+        )
+        .unwrap();
+        assert!(result.next_statement().unwrap().check().is_ok());
+        assert_eq!(
+            result.next_statement().unwrap().check().unwrap_err(),
+            Error::DatabaseError {
+                error: "database not found: db".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_next() {
+        let mut result = result();
+        assert!(result.deserialize_next::<Weather>().is_ok());
+        assert!(matches!(
+            result.deserialize_next::<Weather>(),
+            Err(Error::DatabaseError { .. })
+        ));
+        assert!(result.deserialize_next::<Weather>().is_ok());
+        assert!(matches!(
+            result.deserialize_next::<Weather>(),
+            Err(Error::DeserializationError { .. })
+        ));
     }
 }
@@ -0,0 +1,629 @@
+//! Serialization of arbitrary `Serialize` types into points.
+//!
+//! The fields of a struct or map are converted into the tags and fields of a point, as described
+//! by a [`PointSchema`]. Nested structs and maps are flattened, joining the names with a
+//! separator, and `None` values are skipped.
+//!
+//! ```rust
+//! use influxdb::integrations::serde_integration::PointSchema;
+//! use influxdb::Query;
+//! use serde_derive::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Position {
+//!     lat: f64,
+//!     lon: f64,
+//! }
+//!
+//! #[derive(Serialize)]
+//! struct Weather {
+//!     time: u64,
+//!     #[serde(rename = "tag_location")]
+//!     location: String,
+//!     temperature: i32,
+//!     humidity: Option<i32>,
+//!     position: Position,
+//! }
+//!
+//! let weather = Weather {
+//!     time: 11,
+//!     location: "berlin".to_string(),
+//!     temperature: 21,
+//!     humidity: None,
+//!     position: Position { lat: 52.5, lon: 13.4 },
+//! };
+//! let query = PointSchema::new("weather").to_write_query(&weather).unwrap();
+//! assert_eq!(
+//!     query.build().unwrap().get(),
+//!     "weather,location=berlin temperature=21i,position_lat=52.5,position_lon=13.4 11"
+//! );
+//! ```
+
+use serde::ser::{self, Impossible, Serialize, SerializeMap, SerializeStruct, Serializer};
+use std::fmt::{self, Display};
+
+use super::timestamp::parse_rfc3339;
+use crate::{Error, Precision, Timestamp, Type, WriteQuery};
+
+/// Describes how the fields of a `Serialize` type are converted into a point.
+///
+/// By default, the field named `time` is the timestamp of the point, fields whose name starts
+/// with `tag_` are written as tags without that prefix, and all other fields are written as
+/// fields. The time field may contain an integer counted in the [precision](Self::precision) of
+/// the schema, or an RFC3339 string like the ones `chrono`, `time` and `jiff` serialize.
+#[derive(Debug, Clone)]
+pub struct PointSchema {
+    measurement: String,
+    measurement_field: Option<String>,
+    time_field: String,
+    precision: Precision,
+    tags: Vec<String>,
+    tag_prefix: Option<String>,
+    separator: String,
+}
+
+impl PointSchema {
+    /// Creates a new [`PointSchema`] writing points to `measurement`
+    pub fn new<S>(measurement: S) -> Self
+    where
+        S: Into<String>,
+    {
+        PointSchema {
+            measurement: measurement.into(),
+            measurement_field: None,
+            time_field: "time".to_string(),
+            precision: Precision::Nanoseconds,
+            tags: Vec::new(),
+            tag_prefix: Some("tag_".to_string()),
+            separator: "_".to_string(),
+        }
+    }
+
+    /// Takes the measurement of every point from this field instead
+    #[must_use]
+    pub fn measurement_field<S>(mut self, field: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.measurement_field = Some(field.into());
+        self
+    }
+
+    /// Takes the timestamp of every point from this field, `time` by default
+    #[must_use]
+    pub fn time_field<S>(mut self, field: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.time_field = field.into();
+        self
+    }
+
+    /// The precision of integer times, nanoseconds by default
+    #[must_use]
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Writes these fields as tags, in addition to the fields starting with the tag prefix
+    #[must_use]
+    pub fn tags<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tags = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Writes fields starting with `prefix` as tags, `tag_` by default. `None` disables the
+    /// prefix.
+    #[must_use]
+    pub fn tag_prefix<S>(mut self, prefix: Option<S>) -> Self
+    where
+        S: Into<String>,
+    {
+        self.tag_prefix = prefix.map(Into::into);
+        self
+    }
+
+    /// Joins the names of nested fields with `separator`, `_` by default
+    #[must_use]
+    pub fn separator<S>(mut self, separator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Converts `value`, which has to serialize as a struct or map, into a point
+    pub fn to_write_query<T>(&self, value: &T) -> Result<WriteQuery, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut values = Vec::new();
+        value
+            .serialize(Flatten {
+                path: None,
+                separator: &self.separator,
+                values: &mut values,
+            })
+            .map_err(|err| Error::InvalidQueryError {
+                error: format!("could not serialize point: {err}"),
+            })?;
+
+        let mut measurement = self.measurement.clone();
+        let mut timestamp = None;
+        let mut tags = Vec::new();
+        let mut fields = Vec::new();
+        for (name, value) in values {
+            if Some(&name) == self.measurement_field.as_ref() {
+                measurement = value.to_string();
+            } else if name == self.time_field {
+                timestamp = Some(self.timestamp(value)?);
+            } else if self.tags.contains(&name) {
+                tags.push((name, value));
+            } else if let Some(tag) = self
+                .tag_prefix
+                .as_deref()
+                .and_then(|prefix| name.strip_prefix(prefix))
+            {
+                tags.push((tag.to_string(), value));
+            } else {
+                fields.push((name, value));
+            }
+        }
+
+        let timestamp = timestamp.ok_or_else(|| Error::InvalidQueryError {
+            error: format!("missing time field {}", self.time_field),
+        })?;
+        let mut query = WriteQuery::new(timestamp, measurement);
+        query.tags = tags;
+        query.fields = fields;
+        Ok(query)
+    }
+
+    /// Converts all `values` into points, see [`to_write_query`](Self::to_write_query)
+    pub fn to_write_queries<I>(&self, values: I) -> Result<Vec<WriteQuery>, Error>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        values
+            .into_iter()
+            .map(|value| self.to_write_query(&value))
+            .collect()
+    }
+
+    fn timestamp(&self, value: Type) -> Result<Timestamp, Error> {
+        let invalid = |value: &Type| Error::InvalidQueryError {
+            error: format!("invalid time {value}"),
+        };
+        match value {
+            Type::UnsignedInteger(time) => {
+                Ok(Timestamp::from_precision(time.into(), self.precision))
+            }
+            Type::SignedInteger(time) => u128::try_from(time)
+                .map(|time| Timestamp::from_precision(time, self.precision))
+                .map_err(|_| invalid(&value)),
+            Type::Text(ref time) => {
+                parse_rfc3339(time)
+                    .map(Timestamp::Nanoseconds)
+                    .map_err(|err| Error::InvalidQueryError {
+                        error: format!("invalid time {value}: {err}"),
+                    })
+            }
+            value => Err(invalid(&value)),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SerError(String);
+
+impl Display for SerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SerError {}
+
+impl ser::Error for SerError {
+    fn custom<T: Display>(msg: T) -> Self {
+        SerError(msg.to_string())
+    }
+}
+
+// Serializer collecting the flattened values of a struct or map. Values are named after the
+// path of their fields; `path` is `None` for the top level, which has to be a struct or map.
+struct Flatten<'a> {
+    path: Option<String>,
+    separator: &'a str,
+    values: &'a mut Vec<(String, Type)>,
+}
+
+impl Flatten<'_> {
+    fn push<T: Into<Type>>(self, value: T) -> Result<(), SerError> {
+        match self.path {
+            Some(path) => {
+                self.values.push((path, value.into()));
+                Ok(())
+            }
+            None => Err(SerError("expected a struct or map".to_string())),
+        }
+    }
+
+    fn unsupported(self, kind: &str) -> SerError {
+        match self.path {
+            Some(path) => SerError(format!("{kind} are not supported, found in {path}")),
+            None => SerError("expected a struct or map".to_string()),
+        }
+    }
+}
+
+impl<'a> Serializer for Flatten<'a> {
+    type Ok = ();
+    type Error = SerError;
+    type SerializeSeq = Impossible<(), SerError>;
+    type SerializeTuple = Impossible<(), SerError>;
+    type SerializeTupleStruct = Impossible<(), SerError>;
+    type SerializeTupleVariant = Impossible<(), SerError>;
+    type SerializeMap = FlattenCompound<'a>;
+    type SerializeStruct = FlattenCompound<'a>;
+    type SerializeStructVariant = Impossible<(), SerError>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), SerError> {
+        let v = Type::try_from(v).map_err(ser::Error::custom)?;
+        self.push(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), SerError> {
+        let v = Type::try_from(v).map_err(ser::Error::custom)?;
+        self.push(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), SerError> {
+        self.push(v)
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), SerError> {
+        Err(self.unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<(), SerError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), SerError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), SerError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), SerError> {
+        self.push(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), SerError> {
+        Err(self.unsupported("enum variants with values"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, SerError> {
+        Err(self.unsupported("sequences"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, SerError> {
+        Err(self.unsupported("tuples"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, SerError> {
+        Err(self.unsupported("tuple structs"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerError> {
+        Err(self.unsupported("enum variants with values"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, SerError> {
+        Ok(FlattenCompound {
+            path: self.path,
+            separator: self.separator,
+            values: self.values,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, SerError> {
+        self.serialize_map(None)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, SerError> {
+        Err(self.unsupported("enum variants with values"))
+    }
+}
+
+struct FlattenCompound<'a> {
+    path: Option<String>,
+    separator: &'a str,
+    values: &'a mut Vec<(String, Type)>,
+    key: Option<String>,
+}
+
+impl FlattenCompound<'_> {
+    fn field<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), SerError> {
+        let path = match &self.path {
+            Some(path) => format!("{path}{}{key}", self.separator),
+            None => key.to_string(),
+        };
+        value.serialize(Flatten {
+            path: Some(path),
+            separator: self.separator,
+            values: self.values,
+        })
+    }
+}
+
+impl SerializeMap for FlattenCompound<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerError> {
+        let mut values = Vec::new();
+        key.serialize(Flatten {
+            path: Some(String::new()),
+            separator: self.separator,
+            values: &mut values,
+        })?;
+        match values.as_slice() {
+            [(_, key)] => {
+                self.key = Some(key.to_string());
+                Ok(())
+            }
+            _ => Err(SerError(
+                "map keys have to be strings or numbers".to_string(),
+            )),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| SerError("map value without a key".to_string()))?;
+        self.field(&key, value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        Ok(())
+    }
+}
+
+impl SerializeStruct for FlattenCompound<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointSchema;
+    use crate::{Error, Precision, Query};
+    use serde_derive::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Kind {
+        Indoor,
+    }
+
+    #[derive(Serialize)]
+    struct Sensor {
+        id: u32,
+        kind: Kind,
+    }
+
+    #[derive(Serialize)]
+    struct Reading {
+        time: i64,
+        room: String,
+        value: f64,
+        comment: Option<String>,
+        sensor: Sensor,
+    }
+
+    fn reading() -> Reading {
+        Reading {
+            time: 7,
+            room: "kitchen".to_string(),
+            value: 1.5,
+            comment: None,
+            sensor: Sensor {
+                id: 3,
+                kind: Kind::Indoor,
+            },
+        }
+    }
+
+    #[test]
+    fn test_schema_tags_and_separator() {
+        let query = PointSchema::new("readings")
+            .tags(["room", "sensor.kind"])
+            .separator(".")
+            .precision(Precision::Seconds)
+            .to_write_query(&reading())
+            .unwrap();
+        assert_eq!(
+            query.build().unwrap().get(),
+            "readings,room=kitchen,sensor.kind=indoor value=1.5,sensor.id=3i 7"
+        );
+    }
+
+    #[test]
+    fn test_measurement_field_and_rfc3339_time() {
+        let mut point = BTreeMap::new();
+        point.insert("at", "1970-01-01T00:00:01Z");
+        point.insert("name", "cpu");
+        point.insert("tag_host", "a");
+        point.insert("state", "idle");
+        let query = PointSchema::new("unused")
+            .measurement_field("name")
+            .time_field("at")
+            .to_write_query(&point)
+            .unwrap();
+        assert_eq!(
+            query.build().unwrap().get(),
+            "cpu,host=a state=\"idle\" 1000000000"
+        );
+    }
+
+    #[test]
+    fn test_invalid_points() {
+        let schema = PointSchema::new("m");
+        assert!(matches!(
+            schema.to_write_query(&1),
+            Err(Error::InvalidQueryError { .. })
+        ));
+
+        let mut point = BTreeMap::new();
+        point.insert("value", vec![1, 2]);
+        assert!(schema.to_write_query(&point).is_err());
+
+        let mut point = BTreeMap::new();
+        point.insert("value", 1);
+        assert_eq!(
+            schema.to_write_query(&point).unwrap_err(),
+            Error::InvalidQueryError {
+                error: "missing time field time".to_string()
+            }
+        );
+
+        point.insert("time", -1);
+        assert!(schema.to_write_query(&point).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_time() {
+        #[derive(Serialize)]
+        struct Point {
+            time: chrono::DateTime<chrono::Utc>,
+            value: bool,
+        }
+
+        let queries = PointSchema::new("m")
+            .to_write_queries([Point {
+                time: chrono::DateTime::from_timestamp(2, 0).unwrap(),
+                value: true,
+            }])
+            .unwrap();
+        assert_eq!(queries[0].build().unwrap().get(), "m value=true 2000000000");
+    }
+}
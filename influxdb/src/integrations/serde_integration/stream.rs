@@ -0,0 +1,251 @@
+//! Streaming deserialization of rows, see [`Client::query_stream`].
+
+use futures_util::{stream, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use super::Series;
+use crate::{Client, Error, Query};
+
+impl Client {
+    /// Sends a query and deserializes the rows of all series of all its statements into `T`
+    /// while the response is received.
+    ///
+    /// The results are requested in chunks, which InfluxDB sends as newline-separated JSON
+    /// objects of at most 10000 rows. Every chunk is deserialized straight into rows as soon as
+    /// it is received, so only a single chunk is kept in memory.
+    ///
+    /// Rows are deserialized like the values of [`Series`], so tags of grouped series are not
+    /// part of the rows. Errors InfluxDB reports for a statement end the stream with an
+    /// [`Error::DatabaseError`].
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    /// use influxdb::{Client, ReadQuery};
+    /// use serde_derive::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Weather {
+    ///     time: String,
+    ///     temperature: i32,
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), influxdb::Error> {
+    /// let client = Client::new("http://localhost:8086", "test");
+    /// let query = ReadQuery::new("SELECT temperature FROM weather");
+    /// let mut rows = Box::pin(client.query_stream::<Weather, _>(query).await?);
+    /// while let Some(weather) = rows.next().await {
+    ///     let weather = weather?;
+    ///     println!("{}: {}", weather.time, weather.temperature);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_stream<T, Q>(
+        &self,
+        q: Q,
+    ) -> Result<impl Stream<Item = Result<T, Error>>, Error>
+    where
+        T: DeserializeOwned,
+        Q: Query,
+    {
+        let res = self.request(&q, true).await?;
+        Ok(rows(res.bytes_stream()))
+    }
+}
+
+#[derive(Deserialize)]
+struct Chunk<T> {
+    #[serde(default = "Vec::new")]
+    results: Vec<ChunkResult<T>>,
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ChunkResult<T> {
+    error: Option<String>,
+    #[serde(default = "Vec::new")]
+    series: Vec<Series<T>>,
+}
+
+/// Deserializes the rows of a response body, which consists of newline-separated chunks
+fn rows<T, S, B, E>(body: S) -> impl Stream<Item = Result<T, Error>>
+where
+    T: DeserializeOwned,
+    S: Stream<Item = Result<B, E>>,
+    B: AsRef<[u8]>,
+    E: Display,
+{
+    let state = RowStream {
+        body: Box::pin(body),
+        buffer: Vec::new(),
+        searched: 0,
+        rows: VecDeque::new(),
+        done: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        let row = state.next().await?;
+        Some((row, state))
+    })
+}
+
+struct RowStream<S, T> {
+    body: std::pin::Pin<Box<S>>,
+    buffer: Vec<u8>,
+    // the length of the prefix of `buffer` that contains no newline
+    searched: usize,
+    rows: VecDeque<T>,
+    done: bool,
+}
+
+impl<S, T, B, E> RowStream<S, T>
+where
+    T: DeserializeOwned,
+    S: Stream<Item = Result<B, E>>,
+    B: AsRef<[u8]>,
+    E: Display,
+{
+    async fn next(&mut self) -> Option<Result<T, Error>> {
+        loop {
+            if let Some(row) = self.rows.pop_front() {
+                return Some(Ok(row));
+            }
+            if self.done {
+                return None;
+            }
+
+            if let Some(end) = self.buffer[self.searched..]
+                .iter()
+                .position(|byte| *byte == b'\n')
+            {
+                let end = self.searched + end;
+                let chunk = self.parse(..end);
+                self.buffer.drain(..=end);
+                self.searched = 0;
+                if let Err(err) = chunk {
+                    return Some(Err(self.fail(err)));
+                }
+                continue;
+            }
+            self.searched = self.buffer.len();
+
+            match self.body.next().await {
+                Some(Ok(bytes)) => self.buffer.extend_from_slice(bytes.as_ref()),
+                Some(Err(err)) => {
+                    return Some(Err(self.fail(Error::ProtocolError {
+                        error: err.to_string(),
+                    })))
+                }
+                None => {
+                    self.done = true;
+                    let len = self.buffer.len();
+                    if let Err(err) = self.parse(..len) {
+                        return Some(Err(self.fail(err)));
+                    }
+                    self.buffer = Vec::new();
+                }
+            }
+        }
+    }
+
+    /// Deserializes the rows of the chunk in `self.buffer[range]`
+    fn parse(&mut self, range: std::ops::RangeTo<usize>) -> Result<(), Error> {
+        let chunk = &self.buffer[range];
+        if chunk.iter().all(u8::is_ascii_whitespace) {
+            return Ok(());
+        }
+        let chunk = serde_json::from_slice::<Chunk<T>>(chunk).map_err(|err| {
+            Error::DeserializationError {
+                error: format!("serde error: {err}"),
+            }
+        })?;
+        if let Some(error) = chunk.error {
+            return Err(Error::DatabaseError { error });
+        }
+        for result in chunk.results {
+            if let Some(error) = result.error {
+                return Err(Error::DatabaseError { error });
+            }
+            for series in result.series {
+                self.rows.extend(series.values);
+            }
+        }
+        Ok(())
+    }
+
+    fn fail(&mut self, err: Error) -> Error {
+        self.done = true;
+        self.rows.clear();
+        self.buffer = Vec::new();
+        err
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rows;
+    use crate::Error;
+    use futures_util::{stream, StreamExt};
+    use serde_derive::Deserialize;
+    use std::convert::Infallible;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Weather {
+        temperature: i32,
+    }
+
+    async fn collect(chunks: Vec<&'static str>) -> Vec<Result<Weather, Error>> {
+        let body = stream::iter(chunks.into_iter().map(Ok::<_, Infallible>));
+        rows(body).collect().await
+    }
+
+    #[tokio::test]
+    async fn test_rows_split_across_chunks() {
+        let rows = collect(vec![
+            r#"{"results":[{"statement_id":0,"series":[{"name":"weather","tags":{"city":"berlin"},"#,
+            r#""columns":["time","temperature"],"values":[[1,21],[2,22]],"partial":true}],"partial":true}]}"#,
+            "\n{\"results\":[{\"statement_id\":0,\"series\":[{\"name\":\"weather\",",
+            r#""columns":["time","temperature"],"values":[[3,23]]}]}]}"#,
+        ])
+        .await;
+        assert_eq!(
+            rows.into_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+            vec![
+                Weather { temperature: 21 },
+                Weather { temperature: 22 },
+                Weather { temperature: 23 },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rows_statement_error() {
+        let rows = collect(vec![
+            "{\"results\":[{\"statement_id\":0,\"series\":[{\"name\":\"weather\",\"columns\":[\"temperature\"],\"values\":[[21]]}]}]}\n",
+            "{\"results\":[{\"statement_id\":1,\"error\":\"database not found: db\"}]}\n",
+            "{\"results\":[{\"statement_id\":2}]}\n",
+        ])
+        .await;
+        assert_eq!(
+            rows,
+            vec![
+                Ok(Weather { temperature: 21 }),
+                Err(Error::DatabaseError {
+                    error: "database not found: db".to_string()
+                }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rows_invalid_chunk() {
+        let rows = collect(vec!["{\"results\":[\n"]).await;
+        assert!(matches!(
+            rows.as_slice(),
+            [Err(Error::DeserializationError { .. })]
+        ));
+    }
+}
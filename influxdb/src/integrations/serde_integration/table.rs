@@ -0,0 +1,776 @@
+//! Schema-less query results.
+//!
+//! A [`ResultTable`] holds the series of a statement result without deserializing them into a
+//! Rust type known at compile time, which is useful for ad-hoc queries and admin tools.
+//!
+//! ```rust,no_run
+//! use influxdb::{Client, ReadQuery};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), influxdb::Error> {
+//! let client = Client::new("http://localhost:8086", "test");
+//! let query = ReadQuery::new("SELECT * FROM weather GROUP BY location");
+//! let table = client.json_query(query).await?.next_table()?;
+//! for series in &table.series {
+//!     for row in series.rows() {
+//!         println!(
+//!             "{} {:?} {:?}",
+//!             series.name,
+//!             series.tags.get("location"),
+//!             row.get("temperature").and_then(|value| value.as_f64())
+//!         );
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Display, Formatter};
+
+use super::{timestamp, StatementResult};
+use crate::{Error, Precision, Timestamp, Type, WriteQuery};
+
+/// The type of a field, as listed by a `SHOW FIELD KEYS` query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldType {
+    Float,
+    Integer,
+    Unsigned,
+    String,
+    Boolean,
+}
+
+impl FieldType {
+    /// Returns the type called `name` in the `fieldType` column of `SHOW FIELD KEYS`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "float" => Some(FieldType::Float),
+            "integer" => Some(FieldType::Integer),
+            "unsigned" => Some(FieldType::Unsigned),
+            "string" => Some(FieldType::String),
+            "boolean" => Some(FieldType::Boolean),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FieldType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FieldType::Float => "float",
+            FieldType::Integer => "integer",
+            FieldType::Unsigned => "unsigned",
+            FieldType::String => "string",
+            FieldType::Boolean => "boolean",
+        })
+    }
+}
+
+/// Returns the types of the fields listed by a `SHOW FIELD KEYS` query
+pub fn field_types(show_field_keys: &ResultTable) -> HashMap<String, FieldType> {
+    show_field_keys
+        .rows()
+        .filter_map(|row| {
+            let field_type = FieldType::from_name(row.get("fieldType")?.as_str()?)?;
+            Some((row.get("fieldKey")?.as_str()?.to_owned(), field_type))
+        })
+        .collect()
+}
+
+/// The series of a statement result, see the [module documentation](self)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultTable {
+    /// The index of the statement in the query
+    pub statement_id: usize,
+    pub series: Vec<DynamicSeries>,
+}
+
+impl ResultTable {
+    pub(crate) fn from_statement_result(result: &StatementResult) -> Result<Self, Error> {
+        let series = result.series::<RawSeries>()?;
+        let epoch = result.epoch.unwrap_or(Precision::Nanoseconds);
+        Ok(ResultTable {
+            statement_id: result.statement_id,
+            series: series
+                .into_iter()
+                .map(|series| DynamicSeries::from_raw(series, epoch))
+                .collect(),
+        })
+    }
+
+    /// Returns the rows of all series
+    pub fn rows(&self) -> impl Iterator<Item = DynamicRow<'_>> {
+        self.series.iter().flat_map(DynamicSeries::rows)
+    }
+
+    /// Converts all rows of all series into points, see [`DynamicSeries::to_write_queries`].
+    ///
+    /// Integers are written as floats if their column contains floats in any series of the same
+    /// measurement.
+    pub fn to_write_queries(&self) -> Result<Vec<WriteQuery>, Error> {
+        self.to_write_queries_with_field_types(&HashMap::new())
+    }
+
+    /// Converts all rows of all series into points like [`to_write_queries`](Self::to_write_queries),
+    /// but writes the fields in `field_types`, e.g. from [`field_types`], as the given types.
+    ///
+    /// Fails if a value cannot be converted into the type of its field.
+    pub fn to_write_queries_with_field_types(
+        &self,
+        field_types: &HashMap<String, FieldType>,
+    ) -> Result<Vec<WriteQuery>, Error> {
+        let mut column_types = HashMap::<(&str, &str), ValueType>::new();
+        for series in &self.series {
+            for (idx, column) in series.columns.iter().enumerate() {
+                let column_type = column_types
+                    .entry((series.name.as_str(), column.as_str()))
+                    .or_insert(ValueType::Null);
+                *column_type = series.values.iter().fold(*column_type, |column_type, row| {
+                    column_type.widen(&row[idx])
+                });
+            }
+        }
+
+        let mut queries = Vec::new();
+        for series in &self.series {
+            let column_types = series
+                .columns
+                .iter()
+                .map(|column| match field_types.get(column) {
+                    Some(field_type) => Some(*field_type),
+                    None => column_types[&(series.name.as_str(), column.as_str())].field_type(),
+                })
+                .collect::<Vec<_>>();
+            for row in series.rows() {
+                queries.extend(row.write_query(&column_types)?);
+            }
+        }
+        Ok(queries)
+    }
+}
+
+/// A series of a [`ResultTable`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicSeries {
+    /// The name of the series, i.e. the measurement
+    pub name: String,
+    /// The tags of the series, only returned for `GROUP BY` queries
+    pub tags: BTreeMap<String, String>,
+    pub columns: Vec<String>,
+    pub values: Vec<Vec<FieldValue>>,
+}
+
+impl DynamicSeries {
+    fn from_raw(series: RawSeries, epoch: Precision) -> Self {
+        let time_column = series.columns.iter().position(|column| column == "time");
+        let values = series
+            .values
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .enumerate()
+                    .map(|(idx, value)| match Some(idx) == time_column {
+                        true => FieldValue::time(value, epoch),
+                        false => FieldValue::from(value),
+                    })
+                    .collect()
+            })
+            .collect();
+        DynamicSeries {
+            name: series.name,
+            tags: series
+                .tags
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, value)| match value {
+                    Value::String(value) => (key, value),
+                    value => (key, value.to_string()),
+                })
+                .collect(),
+            columns: series.columns,
+            values,
+        }
+    }
+
+    /// Returns the index of the column `name`
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column == name)
+    }
+
+    /// Returns the values of the column `name`
+    pub fn column(&self, name: &str) -> Option<impl Iterator<Item = &FieldValue>> {
+        let idx = self.column_index(name)?;
+        Some(self.values.iter().map(move |row| &row[idx]))
+    }
+
+    /// Returns the rows of this series
+    pub fn rows(&self) -> impl Iterator<Item = DynamicRow<'_>> {
+        self.values.iter().map(move |values| DynamicRow {
+            series: self,
+            values,
+        })
+    }
+
+    /// Converts all rows into points of the measurement [`name`](Self::name).
+    ///
+    /// The tags of the series are written as tags, all other columns except `time` as fields.
+    /// Null values are skipped, as are rows without any other values, like the empty intervals
+    /// of `GROUP BY time(..) fill(null)`. Use `GROUP BY *` so that tags are returned as series
+    /// tags instead of columns.
+    ///
+    /// InfluxDB returns floats without a fractional part as integers, so integers are written
+    /// as floats if their column contains any float.
+    pub fn to_write_queries(&self) -> Result<Vec<WriteQuery>, Error> {
+        let column_types = (0..self.columns.len())
+            .map(|idx| ValueType::infer(self.values.iter().map(|row| &row[idx])).field_type())
+            .collect::<Vec<_>>();
+        self.rows()
+            .filter_map(|row| row.write_query(&column_types).transpose())
+            .collect()
+    }
+}
+
+/// A row of a [`DynamicSeries`]
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicRow<'a> {
+    series: &'a DynamicSeries,
+    values: &'a [FieldValue],
+}
+
+impl<'a> DynamicRow<'a> {
+    /// Returns the series of this row
+    pub fn series(&self) -> &'a DynamicSeries {
+        self.series
+    }
+
+    /// Returns the values of this row, in the order of the columns of the series
+    pub fn values(&self) -> &'a [FieldValue] {
+        self.values
+    }
+
+    /// Returns the value of the column `name`
+    pub fn get(&self, name: &str) -> Option<&'a FieldValue> {
+        self.series
+            .column_index(name)
+            .and_then(|idx| self.values.get(idx))
+    }
+
+    /// Returns the value of the tag `name`, either of the series or of its column
+    pub fn tag(&self, name: &str) -> Option<&'a str> {
+        self.series
+            .tags
+            .get(name)
+            .map(String::as_str)
+            .or_else(|| self.get(name).and_then(FieldValue::as_str))
+    }
+
+    /// Returns the `time` column
+    pub fn time(&self) -> Option<Timestamp> {
+        self.get("time").and_then(FieldValue::as_timestamp)
+    }
+
+    /// Iterates over the column names and values of this row
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a FieldValue)> {
+        self.series
+            .columns
+            .iter()
+            .map(String::as_str)
+            .zip(self.values)
+    }
+
+    /// Converts this row into a point, see [`DynamicSeries::to_write_queries`].
+    ///
+    /// As only this row is known, integers are always written as integers, even if InfluxDB
+    /// returned them for a float field. Prefer [`DynamicSeries::to_write_queries`] to write the
+    /// rows of a series.
+    ///
+    /// Fails if all values except `time` are null, as a point needs at least one field.
+    pub fn to_write_query(&self) -> Result<WriteQuery, Error> {
+        self.write_query(&[])?
+            .ok_or_else(|| Error::InvalidQueryError {
+                error: format!(
+                    "the row at {:?} of {} has no non-null fields",
+                    self.time(),
+                    self.series.name
+                ),
+            })
+    }
+
+    /// Converts this row into a point, writing every value as the type of its column in
+    /// `column_types`, or returns `None` if all values except `time` are null
+    fn write_query(&self, column_types: &[Option<FieldType>]) -> Result<Option<WriteQuery>, Error> {
+        let time = self.time().ok_or_else(|| Error::InvalidQueryError {
+            error: "rows without a time column cannot be written".to_string(),
+        })?;
+        let mut query = WriteQuery::new(time, self.series.name.as_str());
+        for (key, value) in &self.series.tags {
+            query = query.add_tag(key.as_str(), value.as_str());
+        }
+        let mut has_fields = false;
+        for (idx, (key, value)) in self.iter().enumerate() {
+            if key == "time" || value.is_null() {
+                continue;
+            }
+            let value = match column_types.get(idx).copied().flatten() {
+                Some(field_type) => {
+                    value
+                        .to_field_type(field_type)
+                        .ok_or_else(|| Error::InvalidQueryError {
+                            error: format!(
+                            "could not convert {value:?} in column {key} of the row at {time:?} \
+                             of {} to {field_type}",
+                            self.series.name
+                        ),
+                        })?
+                }
+                None => value.to_type().ok_or_else(|| Error::InvalidQueryError {
+                    error: format!("{value:?} in column {key} cannot be written as a field"),
+                })?,
+            };
+            query = query.add_field(key, value);
+            has_fields = true;
+        }
+        Ok(has_fields.then_some(query))
+    }
+}
+
+/// A value of a [`DynamicSeries`], mirroring [`Type`]
+///
+/// InfluxDB returns floats without a fractional part as integers, so [`as_f64`](Self::as_f64)
+/// accepts integers too.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Null,
+    Boolean(bool),
+    Float(f64),
+    SignedInteger(i64),
+    UnsignedInteger(u64),
+    Text(String),
+    /// The `time` column
+    Time(Timestamp),
+}
+
+impl FieldValue {
+    fn time(value: Value, epoch: Precision) -> Self {
+        timestamp::deserialize::<_, Timestamp>(&value, epoch)
+            .map(FieldValue::Time)
+            .unwrap_or_else(|_| FieldValue::from(value))
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, FieldValue::Null)
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FieldValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            FieldValue::Float(value) => Some(*value),
+            FieldValue::SignedInteger(value) => Some(*value as f64),
+            FieldValue::UnsignedInteger(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            FieldValue::SignedInteger(value) => Some(*value),
+            FieldValue::UnsignedInteger(value) => (*value).try_into().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            FieldValue::SignedInteger(value) => (*value).try_into().ok(),
+            FieldValue::UnsignedInteger(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FieldValue::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_timestamp(&self) -> Option<Timestamp> {
+        match self {
+            FieldValue::Time(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Converts this value into a [`Type`], returning `None` for [`FieldValue::Null`] and times
+    /// whose nanoseconds do not fit a `u64`
+    pub fn to_type(&self) -> Option<Type> {
+        match self {
+            FieldValue::Null => None,
+            FieldValue::Boolean(value) => Some(Type::Boolean(*value)),
+            FieldValue::Float(value) => Some(Type::Float(*value)),
+            FieldValue::SignedInteger(value) => Some(Type::SignedInteger(*value)),
+            FieldValue::UnsignedInteger(value) => Some(Type::UnsignedInteger(*value)),
+            FieldValue::Text(value) => Some(Type::Text(value.clone())),
+            FieldValue::Time(value) => value
+                .checked_nanos()
+                .and_then(|nanos| nanos.try_into().ok())
+                .map(Type::UnsignedInteger),
+        }
+    }
+}
+
+impl FieldValue {
+    /// Converts this value into a [`Type`] of `field_type`, returning `None` if it cannot be
+    /// converted
+    fn to_field_type(&self, field_type: FieldType) -> Option<Type> {
+        match field_type {
+            FieldType::Float => self.as_f64().map(Type::Float),
+            FieldType::Integer => self.as_i64().map(Type::SignedInteger),
+            FieldType::Unsigned => self.as_u64().map(Type::UnsignedInteger),
+            FieldType::String => self.as_str().map(|value| Type::Text(value.to_owned())),
+            FieldType::Boolean => self.as_bool().map(Type::Boolean),
+        }
+    }
+}
+
+impl From<Value> for FieldValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => FieldValue::Null,
+            Value::Bool(value) => FieldValue::Boolean(value),
+            Value::Number(number) => {
+                if let Some(value) = number.as_i64() {
+                    FieldValue::SignedInteger(value)
+                } else if let Some(value) = number.as_u64() {
+                    FieldValue::UnsignedInteger(value)
+                } else {
+                    FieldValue::Float(number.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            Value::String(value) => FieldValue::Text(value),
+            value => FieldValue::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<Type> for FieldValue {
+    fn from(value: Type) -> Self {
+        match value {
+            Type::Boolean(value) => FieldValue::Boolean(value),
+            Type::Float(value) => FieldValue::Float(value),
+            Type::SignedInteger(value) => FieldValue::SignedInteger(value),
+            Type::UnsignedInteger(value) => FieldValue::UnsignedInteger(value),
+            Type::Text(value) => FieldValue::Text(value),
+        }
+    }
+}
+
+impl Display for FieldValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldValue::Null => f.write_str("null"),
+            FieldValue::Boolean(value) => write!(f, "{value}"),
+            FieldValue::Float(value) => write!(f, "{value}"),
+            FieldValue::SignedInteger(value) => write!(f, "{value}"),
+            FieldValue::UnsignedInteger(value) => write!(f, "{value}"),
+            FieldValue::Text(value) => f.write_str(value),
+            FieldValue::Time(value) => match value.checked_nanos() {
+                Some(nanos) => write!(f, "{nanos}"),
+                None => write!(f, "{value:?}"),
+            },
+        }
+    }
+}
+
+/// A column of several series combined into one table, see [`combined_columns`]
+#[cfg(any(feature = "arrow", feature = "polars"))]
+pub(crate) struct CombinedColumn<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) kind: ColumnKind,
+}
+
+#[cfg(any(feature = "arrow", feature = "polars"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ColumnKind {
+    Time,
+    Tag,
+    Field(ValueType),
+}
+
+/// The narrowest type that can hold all values of a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ValueType {
+    Null,
+    Boolean,
+    Float,
+    SignedInteger,
+    UnsignedInteger,
+    Text,
+}
+
+impl ValueType {
+    fn infer<'a>(values: impl Iterator<Item = &'a FieldValue>) -> Self {
+        values.fold(ValueType::Null, ValueType::widen)
+    }
+
+    /// Returns the type to write the values of a column of this type as, or `None` to write
+    /// every value as its own type
+    fn field_type(self) -> Option<FieldType> {
+        match self {
+            ValueType::Boolean => Some(FieldType::Boolean),
+            ValueType::Float => Some(FieldType::Float),
+            ValueType::SignedInteger => Some(FieldType::Integer),
+            ValueType::UnsignedInteger => Some(FieldType::Unsigned),
+            ValueType::Null | ValueType::Text => None,
+        }
+    }
+
+    /// Returns the narrowest type that can hold `value` and all values of this type
+    fn widen(self, value: &FieldValue) -> Self {
+        use ValueType::*;
+
+        match (self, value) {
+            (value_type, FieldValue::Null) => value_type,
+            (Null | Boolean, FieldValue::Boolean(_)) => Boolean,
+            (Null | SignedInteger, FieldValue::SignedInteger(_)) => SignedInteger,
+            (Null | UnsignedInteger, FieldValue::UnsignedInteger(_)) => UnsignedInteger,
+            (UnsignedInteger, FieldValue::SignedInteger(value)) if *value >= 0 => UnsignedInteger,
+            (SignedInteger, FieldValue::UnsignedInteger(_))
+            | (Null | SignedInteger | UnsignedInteger | Float, FieldValue::Float(_))
+            | (Float, FieldValue::SignedInteger(_) | FieldValue::UnsignedInteger(_)) => Float,
+            _ => Text,
+        }
+    }
+}
+
+/// Returns the columns of `series` combined into one table: `time` if any series has it, the
+/// tags of all series in alphabetical order, and all other columns in the order they appear
+#[cfg(any(feature = "arrow", feature = "polars"))]
+pub(crate) fn combined_columns(series: &[DynamicSeries]) -> Vec<CombinedColumn<'_>> {
+    let tags = series
+        .iter()
+        .flat_map(|series| series.tags.keys().map(String::as_str))
+        .collect::<std::collections::BTreeSet<_>>();
+    let mut columns = Vec::new();
+    if series
+        .iter()
+        .any(|series| series.column_index("time").is_some())
+    {
+        columns.push(CombinedColumn {
+            name: "time",
+            kind: ColumnKind::Time,
+        });
+    }
+    columns.extend(tags.iter().map(|&name| CombinedColumn {
+        name,
+        kind: ColumnKind::Tag,
+    }));
+    for name in series.iter().flat_map(|series| &series.columns) {
+        if name != "time"
+            && !tags.contains(name.as_str())
+            && !columns.iter().any(|column| column.name == name)
+        {
+            let values = series
+                .iter()
+                .flat_map(|series| series.column(name).into_iter().flatten());
+            columns.push(CombinedColumn {
+                name,
+                kind: ColumnKind::Field(ValueType::infer(values)),
+            });
+        }
+    }
+    columns
+}
+
+/// Returns the values of the column or tag `name` of all rows of `series`, or `Null` for series
+/// without it
+#[cfg(any(feature = "arrow", feature = "polars"))]
+pub(crate) fn combined_values<'a>(
+    series: &'a [DynamicSeries],
+    name: &'a str,
+) -> impl Iterator<Item = std::borrow::Cow<'a, FieldValue>> {
+    use std::borrow::Cow;
+
+    series.iter().flat_map(move |series| {
+        let tag = series.tags.get(name);
+        let idx = series.column_index(name);
+        series.values.iter().map(move |row| match (tag, idx) {
+            (Some(tag), _) => Cow::Owned(FieldValue::Text(tag.clone())),
+            (None, Some(idx)) => Cow::Borrowed(&row[idx]),
+            (None, None) => Cow::Owned(FieldValue::Null),
+        })
+    })
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(crate) struct RawSeries {
+    #[serde(default)]
+    name: String,
+    tags: Option<Map<String, Value>>,
+    #[serde(default)]
+    columns: Vec<String>,
+    #[serde(default)]
+    values: Vec<Vec<Value>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field_types, FieldType, FieldValue, ResultTable};
+    use crate::integrations::serde_integration::DatabaseQueryResult;
+    use crate::{Precision, Query, Timestamp};
+
+    fn table(epoch: Option<Precision>) -> ResultTable {
+        let mut result: DatabaseQueryResult = serde_json::from_value(serde_json::json!({
+            "results": [{
+                "statement_id": 0,
+                "series": [{
+                    "name": "weather",
+                    "tags": { "location": "berlin" },
+                    "columns": ["time", "temperature", "raining", "note"],
+                    "values": [
+                        [60, 21.5, false, "sunny"],
+                        ["1970-01-01T00:02:00Z", 22, null, null]
+                    ]
+                }]
+            }]
+        }))
+        .unwrap();
+        result.set_epoch(epoch);
+        result.next_table().unwrap()
+    }
+
+    #[test]
+    fn test_result_table() {
+        let table = table(Some(Precision::Seconds));
+        let series = &table.series[0];
+        assert_eq!(series.name, "weather");
+        assert_eq!(series.column_index("raining"), Some(2));
+        assert_eq!(
+            series
+                .column("temperature")
+                .unwrap()
+                .map(|value| value.as_f64().unwrap())
+                .collect::<Vec<_>>(),
+            vec![21.5, 22.0]
+        );
+
+        let rows = table.rows().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].time(), Some(Timestamp::Seconds(60)));
+        assert_eq!(rows[1].time(), Some(Timestamp::Minutes(2)));
+        assert_eq!(rows[0].tag("location"), Some("berlin"));
+        assert_eq!(rows[0].get("raining"), Some(&FieldValue::Boolean(false)));
+        assert_eq!(
+            rows[0].get("note").and_then(FieldValue::as_str),
+            Some("sunny")
+        );
+        assert!(rows[1].get("note").unwrap().is_null());
+        assert_eq!(rows[1].get("temperature").unwrap().as_i64(), Some(22));
+        assert_eq!(rows[0].get("missing"), None);
+    }
+
+    #[test]
+    fn test_result_table_to_write_queries() {
+        let queries = table(Some(Precision::Seconds)).to_write_queries().unwrap();
+        assert_eq!(
+            queries
+                .iter()
+                .map(|query| query.build().unwrap().get())
+                .collect::<Vec<_>>(),
+            vec![
+                "weather,location=berlin temperature=21.5,raining=false,note=\"sunny\" 60",
+                "weather,location=berlin temperature=22 120000000000",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_result_table_skips_rows_without_fields() {
+        let mut result: DatabaseQueryResult = serde_json::from_value(serde_json::json!({
+            "results": [{
+                "statement_id": 0,
+                "series": [{
+                    "name": "weather",
+                    "columns": ["time", "mean"],
+                    "values": [[0, 21.5], [60, null], [120, 22]]
+                }]
+            }]
+        }))
+        .unwrap();
+        let table = result.next_table().unwrap();
+        let queries = table.to_write_queries().unwrap();
+        assert_eq!(
+            queries
+                .iter()
+                .map(|query| query.build().unwrap().get())
+                .collect::<Vec<_>>(),
+            vec!["weather mean=21.5 0", "weather mean=22 120"]
+        );
+        assert_eq!(table.series[0].to_write_queries().unwrap().len(), 2);
+        assert!(table.rows().nth(1).unwrap().to_write_query().is_err());
+    }
+
+    #[test]
+    fn test_result_table_to_write_queries_with_field_types() {
+        let mut field_keys: DatabaseQueryResult = serde_json::from_value(serde_json::json!({
+            "results": [{
+                "statement_id": 0,
+                "series": [{
+                    "name": "weather",
+                    "columns": ["fieldKey", "fieldType"],
+                    "values": [["temperature", "float"], ["raining", "boolean"]]
+                }]
+            }]
+        }))
+        .unwrap();
+        let field_types = field_types(&field_keys.next_table().unwrap());
+        assert_eq!(field_types["temperature"], FieldType::Float);
+
+        let mut result: DatabaseQueryResult = serde_json::from_value(serde_json::json!({
+            "results": [{
+                "statement_id": 0,
+                "series": [{
+                    "name": "weather",
+                    "columns": ["time", "temperature", "raining"],
+                    "values": [[1, 22, true]]
+                }]
+            }]
+        }))
+        .unwrap();
+        let table = result.next_table().unwrap();
+        assert_eq!(
+            table.to_write_queries().unwrap()[0].build().unwrap(),
+            "weather temperature=22i,raining=true 1"
+        );
+        assert_eq!(
+            table
+                .to_write_queries_with_field_types(&field_types)
+                .unwrap()[0]
+                .build()
+                .unwrap(),
+            "weather temperature=22,raining=true 1"
+        );
+
+        let field_types = [("raining".to_owned(), FieldType::Float)].into();
+        assert!(table
+            .to_write_queries_with_field_types(&field_types)
+            .is_err());
+    }
+
+    #[test]
+    fn test_result_table_integer_time_defaults_to_nanoseconds() {
+        let rows = table(None);
+        assert_eq!(
+            rows.rows().next().unwrap().time(),
+            Some(Timestamp::Nanoseconds(60))
+        );
+    }
+}
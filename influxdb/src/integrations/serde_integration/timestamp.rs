@@ -0,0 +1,354 @@
+//! Deserialization of the `time` column.
+//!
+//! InfluxDB returns times as RFC3339 strings, or as integers counted in the precision set with
+//! [`ReadQuery::with_epoch`](crate::ReadQuery::with_epoch). The functions of this module accept
+//! both and convert them into any type that can be converted from a [`Timestamp`], like
+//! [`Timestamp`] itself, `SystemTime`, `chrono::DateTime<Utc>`, `time::UtcDateTime` or
+//! `jiff::Timestamp`. Integers are interpreted in the precision the function is named after.
+//!
+//! ```rust
+//! use influxdb::integrations::serde_integration::timestamp;
+//! use influxdb::Timestamp;
+//! use serde_derive::Deserialize;
+//! use std::time::SystemTime;
+//!
+//! #[derive(Deserialize)]
+//! struct Weather {
+//!     #[serde(deserialize_with = "timestamp::milliseconds")]
+//!     time: SystemTime,
+//!     temperature: i32,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Humidity {
+//!     #[serde(deserialize_with = "timestamp::nanoseconds")]
+//!     time: Timestamp,
+//!     humidity: i32,
+//! }
+//!
+//! let weather: Weather =
+//!     serde_json::from_str(r#"{ "time": 1500, "temperature": 21 }"#).unwrap();
+//! let humidity: Humidity = serde_json::from_str(
+//!     r#"{ "time": "1970-01-01T00:00:01.5Z", "humidity": 30 }"#,
+//! )
+//! .unwrap();
+//! assert_eq!(Timestamp::try_from(weather.time).unwrap(), humidity.time);
+//! ```
+
+use serde::de::{Deserializer, Error, Unexpected, Visitor};
+use std::fmt::{self, Display, Formatter};
+
+use crate::query::consts::{
+    HOURS_PER_DAY, MILLIS_PER_SECOND, MINUTES_PER_HOUR, NANOS_PER_MILLI, SECONDS_PER_MINUTE,
+};
+use crate::{Precision, Timestamp};
+
+macro_rules! precision_fn {
+        ( $( $name:ident => $precision:ident ),+ ) => (
+                $(
+                    #[doc = concat!(
+                        "Deserializes an RFC3339 string, or an integer counted in ",
+                        stringify!($name),
+                        ", into `T`"
+                    )]
+                    pub fn $name<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                        T: TryFrom<Timestamp>,
+                        T::Error: Display,
+                    {
+                        deserialize(deserializer, Precision::$precision)
+                    }
+                )+
+        )
+}
+precision_fn! {
+    nanoseconds => Nanoseconds,
+    microseconds => Microseconds,
+    milliseconds => Milliseconds,
+    seconds => Seconds,
+    minutes => Minutes,
+    hours => Hours
+}
+
+/// Deserializes an RFC3339 string, or an integer counted in `precision`, into `T`
+pub fn deserialize<'de, D, T>(deserializer: D, precision: Precision) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<Timestamp>,
+    T::Error: Display,
+{
+    let timestamp = deserializer.deserialize_any(TimestampVisitor(precision))?;
+    T::try_from(timestamp).map_err(D::Error::custom)
+}
+
+struct TimestampVisitor(Precision);
+
+impl Visitor<'_> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "an RFC3339 timestamp or a non-negative integer in the precision {}",
+            self.0
+        )
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Timestamp, E> {
+        Ok(Timestamp::from_precision(value.into(), self.0))
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Timestamp, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(value), &self)),
+        }
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Timestamp, E> {
+        match parse_rfc3339(value) {
+            Ok(nanos) => Ok(Timestamp::Nanoseconds(nanos)),
+            Err(Rfc3339Error::Invalid) => Err(E::invalid_value(Unexpected::Str(value), &self)),
+            Err(err) => Err(E::custom(format_args!("{err}, got {value}"))),
+        }
+    }
+}
+
+/// Why a string could not be parsed by [`parse_rfc3339`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Rfc3339Error {
+    Invalid,
+    BeforeEpoch,
+}
+
+impl Display for Rfc3339Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Rfc3339Error::Invalid => f.write_str("invalid RFC3339 timestamp"),
+            Rfc3339Error::BeforeEpoch => {
+                f.write_str("times before the unix epoch are not supported")
+            }
+        }
+    }
+}
+
+/// Parses an RFC3339 timestamp into nanoseconds since the unix epoch
+///
+/// This is the only parser for the time column, so the same strings are accepted whatever
+/// features are enabled. Leap seconds are rejected, because a [`Timestamp`] cannot represent them.
+pub(super) fn parse_rfc3339(value: &str) -> Result<u128, Rfc3339Error> {
+    use Rfc3339Error::Invalid;
+
+    fn number(digits: &[u8]) -> Result<i64, Rfc3339Error> {
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(Invalid);
+        }
+        Ok(digits
+            .iter()
+            .fold(0, |number, digit| number * 10 + i64::from(digit - b'0')))
+    }
+
+    let bytes = value.as_bytes();
+    if bytes.len() < 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return Err(Invalid);
+    }
+    let year = number(&bytes[0..4])?;
+    let month = number(&bytes[5..7])?;
+    let day = number(&bytes[8..10])?;
+    let hour = number(&bytes[11..13])?;
+    let minute = number(&bytes[14..16])?;
+    let second = number(&bytes[17..19])?;
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return Err(Invalid);
+    }
+
+    let mut rest = &bytes[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = fraction
+            .iter()
+            .position(|byte| !byte.is_ascii_digit())
+            .unwrap_or(fraction.len());
+        if len == 0 || len > 9 {
+            return Err(Invalid);
+        }
+        nanos = number(&fraction[..len])? * 10_i64.pow(9 - len as u32);
+        rest = &fraction[len..];
+    }
+
+    let offset = match rest {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+            let (hours, minutes) = (number(&[*h1, *h2])?, number(&[*m1, *m2])?);
+            if hours > 23 || minutes > 59 {
+                return Err(Invalid);
+            }
+            let offset = (hours * MINUTES_PER_HOUR as i64 + minutes) * SECONDS_PER_MINUTE as i64;
+            if *sign == b'+' {
+                offset
+            } else {
+                -offset
+            }
+        }
+        _ => return Err(Invalid),
+    };
+
+    let hours = days_from_civil(year, month, day) * HOURS_PER_DAY as i64 + hour;
+    let minutes = hours * MINUTES_PER_HOUR as i64 + minute;
+    let seconds = minutes * SECONDS_PER_MINUTE as i64 + second - offset;
+    let seconds = u128::try_from(seconds).map_err(|_| Rfc3339Error::BeforeEpoch)?;
+    Ok(seconds * MILLIS_PER_SECOND * NANOS_PER_MILLI + nanos as u128)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The number of days since the unix epoch, see
+/// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_rfc3339, Rfc3339Error};
+    use crate::Timestamp;
+    use serde_derive::Deserialize;
+    use std::time::SystemTime;
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Ok(0));
+        assert_eq!(
+            parse_rfc3339("2021-03-04T05:06:07.123456789Z"),
+            Ok(1_614_834_367_123_456_789)
+        );
+        assert_eq!(
+            parse_rfc3339("2021-03-04T07:06:07.5+02:00"),
+            Ok(1_614_834_367_500_000_000)
+        );
+        assert_eq!(
+            parse_rfc3339("2000-02-29T00:00:00Z"),
+            Ok(951_782_400_000_000_000)
+        );
+        assert_eq!(
+            parse_rfc3339("1969-12-31T23:30:00-01:00"),
+            Ok(1_800_000_000_000)
+        );
+        assert_eq!(
+            parse_rfc3339("1969-12-31T23:59:59Z"),
+            Err(Rfc3339Error::BeforeEpoch)
+        );
+        for invalid in [
+            "2021-02-29T00:00:00Z",
+            "2021-03-04T05:06:07",
+            "2021-03-04T05:06:07.Z",
+            "2021-03-04T05:06:07+99:99",
+            "2021-03-04T05:06:07+24:00",
+            "2021-03-04 05:06:07Z",
+            "2021-03-04T05:06:60Z",
+        ] {
+            assert_eq!(
+                parse_rfc3339(invalid),
+                Err(Rfc3339Error::Invalid),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialize_time_column() {
+        #[derive(Deserialize)]
+        struct Row {
+            #[serde(deserialize_with = "super::seconds")]
+            time: Timestamp,
+        }
+
+        #[derive(Deserialize)]
+        struct SystemTimeRow {
+            #[serde(deserialize_with = "super::hours")]
+            time: SystemTime,
+        }
+
+        let row: Row = serde_json::from_str(r#"{ "time": 60 }"#).unwrap();
+        assert_eq!(row.time, Timestamp::Seconds(60));
+        let row: Row = serde_json::from_str(r#"{ "time": "1970-01-01T00:01:00Z" }"#).unwrap();
+        assert_eq!(row.time, Timestamp::Minutes(1));
+        assert!(serde_json::from_str::<Row>(r#"{ "time": -1 }"#).is_err());
+        assert!(serde_json::from_str::<Row>(r#"{ "time": "yesterday" }"#).is_err());
+        let err = serde_json::from_str::<Row>(r#"{ "time": "1969-12-31T23:59:59Z" }"#)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.starts_with("times before the unix epoch are not supported"));
+
+        let row: SystemTimeRow = serde_json::from_str(r#"{ "time": 1 }"#).unwrap();
+        assert_eq!(Timestamp::try_from(row.time).unwrap(), Timestamp::Hours(1));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_parse_rfc3339_like_chrono() {
+        for value in [
+            "1970-01-01T00:00:00Z",
+            "2021-03-04T05:06:07.123456789Z",
+            "2021-03-04t07:06:07.5+02:00",
+            "2024-02-29T23:59:59.000001-11:30",
+        ] {
+            let time = chrono::DateTime::parse_from_rfc3339(value).unwrap();
+            let nanos = time.timestamp_nanos_opt().unwrap() as u128;
+            assert_eq!(parse_rfc3339(value), Ok(nanos), "{value}");
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_deserialize_chrono_time_column() {
+        use chrono::{DateTime, Utc};
+
+        #[derive(Deserialize)]
+        struct Row {
+            #[serde(deserialize_with = "super::milliseconds")]
+            time: DateTime<Utc>,
+        }
+
+        let row: Row = serde_json::from_str(r#"{ "time": 1500 }"#).unwrap();
+        assert_eq!(row.time.timestamp_millis(), 1500);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_deserialize_time_crate_time_column() {
+        #[derive(Deserialize)]
+        struct Row {
+            #[serde(deserialize_with = "super::microseconds")]
+            time: time::UtcDateTime,
+        }
+
+        let row: Row = serde_json::from_str(r#"{ "time": "1970-01-01T00:00:02Z" }"#).unwrap();
+        assert_eq!(row.time.unix_timestamp(), 2);
+    }
+}
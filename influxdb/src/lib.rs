@@ -123,13 +123,28 @@ mod error;
 mod query;
 
 pub use client::Client;
-pub use error::Error;
+pub use error::{ConversionError, Error};
+pub use query::point::{Point, PointValue, PointWriteType, ToPoint};
 pub use query::read_query::ReadQuery;
-pub use query::write_query::{Type, WriteQuery};
-pub use query::{InfluxDbWriteable, Query, QueryType, Timestamp, ValidQuery};
+pub use query::select_query::SelectQuery;
+pub use query::write_query::{DuplicatePolicy, SeriesKey, Type, WriteQuery};
+#[cfg(feature = "derive")]
+pub use query::InfluxDbValue;
+pub use query::{
+    InfluxDbFields, InfluxDbWriteable, Precision, Query, QueryType, Timestamp, ValidQuery,
+};
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+pub use {error::DecimalRangeError, query::write_query::DecimalPolicy};
+
+#[cfg(feature = "serde")]
+pub use integrations::serde_integration::{InfluxDbReadable, ReadRow};
 
 #[cfg(feature = "serde")]
 pub mod integrations {
+    #[cfg(feature = "arrow")]
+    pub mod arrow_integration;
+    #[cfg(feature = "polars")]
+    pub mod polars_integration;
     #[cfg(feature = "serde")]
     pub mod serde_integration;
 }
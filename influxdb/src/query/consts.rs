@@ -1,3 +1,4 @@
+pub const HOURS_PER_DAY: u128 = 24;
 pub const MINUTES_PER_HOUR: u128 = 60;
 pub const SECONDS_PER_MINUTE: u128 = 60;
 pub const MILLIS_PER_SECOND: u128 = 1000;
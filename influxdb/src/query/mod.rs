@@ -22,20 +22,77 @@
 
 pub mod consts;
 mod line_proto_term;
+pub mod point;
 pub mod read_query;
+pub mod select_query;
 pub mod write_query;
+use std::cmp::Ordering;
 use std::convert::Infallible;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Sub};
+use std::time::{Duration, SystemTime, SystemTimeError, UNIX_EPOCH};
 
+use crate::error::{ConversionError, PrecisionLossError, TimestampTooLargeError};
 use crate::{Error, WriteQuery};
 use consts::{
     MILLIS_PER_SECOND, MINUTES_PER_HOUR, NANOS_PER_MICRO, NANOS_PER_MILLI, SECONDS_PER_MINUTE,
 };
+use point::Point;
 
 #[cfg(feature = "derive")]
-pub use influxdb_derive::InfluxDbWriteable;
+pub use influxdb_derive::{InfluxDbFields, InfluxDbValue, InfluxDbWriteable};
+
+/// The precision of a [`Timestamp`]
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Copy, Clone)]
+pub enum Precision {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    Seconds,
+    Minutes,
+    Hours,
+}
+
+impl Precision {
+    /// The number of nanoseconds in one unit of this precision
+    fn unit_nanos(self) -> u128 {
+        match self {
+            Precision::Nanoseconds => 1,
+            Precision::Microseconds => NANOS_PER_MICRO,
+            Precision::Milliseconds => NANOS_PER_MILLI,
+            Precision::Seconds => MILLIS_PER_SECOND * NANOS_PER_MILLI,
+            Precision::Minutes => SECONDS_PER_MINUTE * MILLIS_PER_SECOND * NANOS_PER_MILLI,
+            Precision::Hours => {
+                MINUTES_PER_HOUR * SECONDS_PER_MINUTE * MILLIS_PER_SECOND * NANOS_PER_MILLI
+            }
+        }
+    }
 
-#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+    /// The name of this precision as used by the `precision` parameter of the write API
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Precision::Nanoseconds => "ns",
+            Precision::Microseconds => "u",
+            Precision::Milliseconds => "ms",
+            Precision::Seconds => "s",
+            Precision::Minutes => "m",
+            Precision::Hours => "h",
+        }
+    }
+}
+
+impl fmt::Display for Precision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A point in time, counted from the unix epoch in the given precision
+///
+/// Timestamps compare equal if they represent the same instant, regardless of their
+/// precision, i.e. `Timestamp::Seconds(1) == Timestamp::Milliseconds(1000)`.
+#[derive(Debug, Copy, Clone)]
 pub enum Timestamp {
     Nanoseconds(u128),
     Microseconds(u128),
@@ -58,6 +115,244 @@ impl Timestamp {
             Timestamp::Nanoseconds(nanos) => *nanos,
         }
     }
+
+    /// Returns the nanoseconds since the unix epoch, or `None` if they don't fit into a `u128`
+    pub fn checked_nanos(&self) -> Option<u128> {
+        self.value().checked_mul(self.precision().unit_nanos())
+    }
+
+    /// Returns the current time, truncated to `precision`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use influxdb::{Precision, Timestamp};
+    ///
+    /// let now = Timestamp::now(Precision::Milliseconds);
+    /// assert_eq!(now.precision(), Precision::Milliseconds);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system clock is set to a time before the unix epoch.
+    pub fn now(precision: Precision) -> Self {
+        Timestamp::try_from(SystemTime::now())
+            .expect("system clock is set before the unix epoch")
+            .truncate_to(precision)
+    }
+
+    /// Creates a timestamp from a count of units of `precision`
+    pub fn from_precision(value: u128, precision: Precision) -> Self {
+        match precision {
+            Precision::Nanoseconds => Timestamp::Nanoseconds(value),
+            Precision::Microseconds => Timestamp::Microseconds(value),
+            Precision::Milliseconds => Timestamp::Milliseconds(value),
+            Precision::Seconds => Timestamp::Seconds(value),
+            Precision::Minutes => Timestamp::Minutes(value),
+            Precision::Hours => Timestamp::Hours(value),
+        }
+    }
+
+    /// Returns the count of units of the precision of this timestamp
+    fn value(&self) -> u128 {
+        match self {
+            Timestamp::Nanoseconds(value)
+            | Timestamp::Microseconds(value)
+            | Timestamp::Milliseconds(value)
+            | Timestamp::Seconds(value)
+            | Timestamp::Minutes(value)
+            | Timestamp::Hours(value) => *value,
+        }
+    }
+
+    /// Returns the precision of this timestamp
+    pub fn precision(&self) -> Precision {
+        match self {
+            Timestamp::Nanoseconds(_) => Precision::Nanoseconds,
+            Timestamp::Microseconds(_) => Precision::Microseconds,
+            Timestamp::Milliseconds(_) => Precision::Milliseconds,
+            Timestamp::Seconds(_) => Precision::Seconds,
+            Timestamp::Minutes(_) => Precision::Minutes,
+            Timestamp::Hours(_) => Precision::Hours,
+        }
+    }
+
+    /// Converts this timestamp to `precision`, failing if that would lose information or the
+    /// value would overflow a `u128`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use influxdb::{Precision, Timestamp};
+    ///
+    /// let ts = Timestamp::Milliseconds(2000);
+    /// assert!(matches!(ts.to_precision(Precision::Seconds), Ok(Timestamp::Seconds(2))));
+    /// assert!(Timestamp::Milliseconds(2001).to_precision(Precision::Seconds).is_err());
+    /// ```
+    pub fn to_precision(self, precision: Precision) -> Result<Self, PrecisionLossError> {
+        let (from, to) = (self.precision().unit_nanos(), precision.unit_nanos());
+        // The units of coarser precisions are multiples of the units of finer ones
+        let value = match to >= from {
+            true if self.value() % (to / from) != 0 => return Err(PrecisionLossError(())),
+            true => self.value() / (to / from),
+            false => self
+                .value()
+                .checked_mul(from / to)
+                .ok_or(PrecisionLossError(()))?,
+        };
+        Ok(Timestamp::from_precision(value, precision))
+    }
+
+    /// Converts this timestamp to `precision`, truncating towards the unix epoch
+    ///
+    /// If this timestamp can't be represented in a finer `precision` without overflowing, it is
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use influxdb::{Precision, Timestamp};
+    ///
+    /// let ts = Timestamp::Milliseconds(2999);
+    /// assert!(matches!(ts.truncate_to(Precision::Seconds), Timestamp::Seconds(2)));
+    /// ```
+    pub fn truncate_to(self, precision: Precision) -> Self {
+        let (from, to) = (self.precision().unit_nanos(), precision.unit_nanos());
+        match to >= from {
+            true => Timestamp::from_precision(self.value() / (to / from), precision),
+            false => match self.value().checked_mul(from / to) {
+                Some(value) => Timestamp::from_precision(value, precision),
+                None => self,
+            },
+        }
+    }
+
+    /// Adds `duration`, returning `None` on overflow
+    ///
+    /// The result keeps the precision of this timestamp, unless `duration` isn't a
+    /// multiple of it, in which case the coarsest precision representing the sum
+    /// exactly is used.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        let nanos = self.checked_nanos()?.checked_add(duration.as_nanos())?;
+        Some(self.with_nanos(nanos, duration))
+    }
+
+    /// Subtracts `duration`, returning `None` if the result would be before the unix epoch
+    ///
+    /// The precision of the result is chosen like for [`checked_add`](Self::checked_add).
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        let nanos = self.checked_nanos()?.checked_sub(duration.as_nanos())?;
+        Some(self.with_nanos(nanos, duration))
+    }
+
+    fn with_nanos(self, nanos: u128, duration: Duration) -> Self {
+        let duration = duration.as_nanos();
+        let precision = [
+            Precision::Hours,
+            Precision::Minutes,
+            Precision::Seconds,
+            Precision::Milliseconds,
+            Precision::Microseconds,
+            Precision::Nanoseconds,
+        ]
+        .into_iter()
+        .filter(|precision| *precision <= self.precision())
+        .find(|precision| duration % precision.unit_nanos() == 0)
+        .unwrap_or(Precision::Nanoseconds);
+        Timestamp::from_precision(nanos / precision.unit_nanos(), precision)
+    }
+
+    /// The key timestamps are compared and hashed by. Timestamps whose nanoseconds overflow a
+    /// `u128` are ordered after all others, by their precision and value.
+    fn key(&self) -> (Option<u128>, Option<(Precision, u128)>) {
+        match self.checked_nanos() {
+            Some(nanos) => (Some(nanos), None),
+            None => (None, Some((self.precision(), self.value()))),
+        }
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (nanos, overflow) = self.key();
+        let (other_nanos, other_overflow) = other.key();
+        // `None` sorts before `Some`, but overflowing timestamps are the latest ones
+        nanos
+            .is_none()
+            .cmp(&other_nanos.is_none())
+            .then(nanos.cmp(&other_nanos))
+            .then(overflow.cmp(&other_overflow))
+    }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// # Panics
+    ///
+    /// Panics on overflow, see [`Timestamp::checked_add`] for a version without panic.
+    fn add(self, duration: Duration) -> Timestamp {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to timestamp")
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// # Panics
+    ///
+    /// Panics if the result would be before the unix epoch, see [`Timestamp::checked_sub`]
+    /// for a version without panic.
+    fn sub(self, duration: Duration) -> Timestamp {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from timestamp")
+    }
+}
+
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = SystemTimeError;
+
+    fn try_from(st: SystemTime) -> Result<Self, Self::Error> {
+        Ok(Timestamp::Nanoseconds(
+            st.duration_since(UNIX_EPOCH)?.as_nanos(),
+        ))
+    }
+}
+
+impl TryFrom<Timestamp> for SystemTime {
+    type Error = TimestampTooLargeError;
+
+    fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
+        let nanos = ts.checked_nanos().ok_or(TimestampTooLargeError(()))?;
+        let secs = (nanos / (MILLIS_PER_SECOND * NANOS_PER_MILLI))
+            .try_into()
+            .map_err(|_| TimestampTooLargeError(()))?;
+        let subsec_nanos = (nanos % (MILLIS_PER_SECOND * NANOS_PER_MILLI)) as u32;
+        UNIX_EPOCH
+            .checked_add(Duration::new(secs, subsec_nanos))
+            .ok_or(TimestampTooLargeError(()))
+    }
 }
 
 impl fmt::Display for Timestamp {
@@ -76,16 +371,16 @@ impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
 
     fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
         use chrono::TimeZone as _;
-        Ok(chrono::Utc.timestamp_nanos(ts.nanos().try_into()?))
+        // `u128::MAX` never fits, so overflowing timestamps fail like too large ones
+        let nanos = ts.checked_nanos().unwrap_or(u128::MAX);
+        Ok(chrono::Utc.timestamp_nanos(nanos.try_into()?))
     }
 }
 
 #[cfg(feature = "chrono")]
 impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
-    type Error = crate::error::TimeTryFromError<
-        crate::error::TimestampTooLargeError,
-        <u128 as TryFrom<i64>>::Error,
-    >;
+    type Error =
+        crate::error::TimeTryFromError<TimestampTooLargeError, <u128 as TryFrom<i64>>::Error>;
 
     fn try_from(dt: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
         // unfortunately chrono doesn't give us the nanos as i128, so we have to error
@@ -107,7 +402,12 @@ impl TryFrom<Timestamp> for time::UtcDateTime {
         crate::error::TimeTryFromError<time::error::ComponentRange, <i128 as TryFrom<u128>>::Error>;
 
     fn try_from(value: Timestamp) -> Result<Self, Self::Error> {
-        let nanos = value.nanos().try_into().map_err(Self::Error::IntError)?;
+        // `u128::MAX` never fits, so overflowing timestamps fail like too large ones
+        let nanos = value
+            .checked_nanos()
+            .unwrap_or(u128::MAX)
+            .try_into()
+            .map_err(Self::Error::IntError)?;
         time::UtcDateTime::from_unix_timestamp_nanos(nanos).map_err(Self::Error::TimeError)
     }
 }
@@ -123,6 +423,48 @@ impl TryFrom<time::UtcDateTime> for Timestamp {
     }
 }
 
+#[cfg(feature = "jiff")]
+impl TryFrom<Timestamp> for jiff::Timestamp {
+    type Error = crate::error::TimeTryFromError<jiff::Error, <i128 as TryFrom<u128>>::Error>;
+
+    fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
+        // `u128::MAX` never fits, so overflowing timestamps fail like too large ones
+        let nanos = ts
+            .checked_nanos()
+            .unwrap_or(u128::MAX)
+            .try_into()
+            .map_err(Self::Error::IntError)?;
+        jiff::Timestamp::from_nanosecond(nanos).map_err(Self::Error::TimeError)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::Timestamp> for Timestamp {
+    type Error = <u128 as TryFrom<i128>>::Error;
+
+    fn try_from(ts: jiff::Timestamp) -> Result<Self, Self::Error> {
+        Ok(Timestamp::Nanoseconds(ts.as_nanosecond().try_into()?))
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<Timestamp> for jiff::Zoned {
+    type Error = crate::error::TimeTryFromError<jiff::Error, <i128 as TryFrom<u128>>::Error>;
+
+    fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
+        Ok(jiff::Timestamp::try_from(ts)?.to_zoned(jiff::tz::TimeZone::UTC))
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::Zoned> for Timestamp {
+    type Error = <u128 as TryFrom<i128>>::Error;
+
+    fn try_from(zoned: jiff::Zoned) -> Result<Self, Self::Error> {
+        zoned.timestamp().try_into()
+    }
+}
+
 pub trait Query {
     /// Builds valid InfluxSQL which can be run against the Database.
     /// In case no fields have been specified, it will return an error,
@@ -175,6 +517,11 @@ pub trait Query {
     fn build_with_opts(&self, use_v2: bool) -> Result<ValidQuery, Error>;
 
     fn get_type(&self) -> QueryType;
+
+    /// The precision in which read queries return times, or `None` for RFC3339 strings
+    fn get_epoch(&self) -> Option<Precision> {
+        None
+    }
 }
 
 impl<Q: Query> Query for &Q {
@@ -189,6 +536,10 @@ impl<Q: Query> Query for &Q {
     fn get_type(&self) -> QueryType {
         Q::get_type(self)
     }
+
+    fn get_epoch(&self) -> Option<Precision> {
+        Q::get_epoch(self)
+    }
 }
 
 impl<Q: Query> Query for Box<Q> {
@@ -203,6 +554,10 @@ impl<Q: Query> Query for Box<Q> {
     fn get_type(&self) -> QueryType {
         Q::get_type(self)
     }
+
+    fn get_epoch(&self) -> Option<Precision> {
+        Q::get_epoch(self)
+    }
 }
 
 pub trait InfluxDbWriteable {
@@ -219,6 +574,28 @@ impl InfluxDbWriteable for Timestamp {
     }
 }
 
+/// A set of tags and fields without a timestamp that can be added to a [`WriteQuery`] or
+/// [`Point`].
+///
+/// This is implemented by `#[derive(InfluxDbFields)]` and `#[derive(InfluxDbWriteable)]`, and
+/// used for fields marked with `#[influxdb(flatten)]`.
+pub trait InfluxDbFields {
+    /// Adds all tags and fields to `query`, prepending `prefix` to their keys.
+    fn add_to_query(self, query: WriteQuery, prefix: &str) -> Result<WriteQuery, ConversionError>;
+
+    /// Adds all tags and fields to `point`, prepending `prefix` to their keys.
+    fn add_to_point<'a>(
+        &'a self,
+        point: Point<'a>,
+        prefix: &str,
+    ) -> Result<Point<'a>, ConversionError>;
+
+    /// Returns the keys of all tags and fields, prepending `prefix` to them.
+    fn keys(prefix: &str) -> Vec<String>
+    where
+        Self: Sized;
+}
+
 #[derive(Debug)]
 #[doc(hidden)]
 pub struct ValidQuery(String);
@@ -280,6 +657,126 @@ mod tests {
         assert!(format!("{}", Timestamp::Nanoseconds(100)) == "100");
     }
 
+    #[test]
+    fn test_timestamp_ordering_across_precisions() {
+        assert_eq!(Timestamp::Seconds(1), Timestamp::Milliseconds(1000));
+        assert!(Timestamp::Seconds(1) < Timestamp::Milliseconds(1001));
+        assert!(Timestamp::Hours(1) > Timestamp::Minutes(59));
+        assert_eq!(
+            Timestamp::Minutes(3).max(Timestamp::Seconds(181)),
+            Timestamp::Seconds(181)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_overflow() {
+        use super::Precision;
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        use std::time::Duration;
+
+        let hash = |ts: &Timestamp| {
+            let mut hasher = DefaultHasher::new();
+            ts.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let max = Timestamp::Hours(u128::MAX);
+        assert_eq!(max.checked_nanos(), None);
+        assert_eq!(max, Timestamp::Hours(u128::MAX));
+        assert_ne!(max, Timestamp::Minutes(u128::MAX));
+        assert!(max > Timestamp::Nanoseconds(u128::MAX));
+        assert!(max > Timestamp::Minutes(u128::MAX));
+        assert_eq!(hash(&max), hash(&Timestamp::Hours(u128::MAX)));
+        assert_eq!(
+            hash(&Timestamp::Seconds(1)),
+            hash(&Timestamp::Milliseconds(1000))
+        );
+        assert!(max.checked_add(Duration::from_secs(1)).is_none());
+        assert!(max.checked_sub(Duration::from_secs(1)).is_none());
+
+        assert!(max.to_precision(Precision::Seconds).is_err());
+        assert!(matches!(
+            max.to_precision(Precision::Hours),
+            Ok(Timestamp::Hours(u128::MAX))
+        ));
+        assert!(matches!(
+            max.truncate_to(Precision::Seconds),
+            Timestamp::Hours(u128::MAX)
+        ));
+        assert!(std::time::SystemTime::try_from(max).is_err());
+        #[cfg(feature = "chrono")]
+        assert!(chrono::DateTime::<chrono::Utc>::try_from(max).is_err());
+        #[cfg(feature = "time")]
+        assert!(time::UtcDateTime::try_from(max).is_err());
+        #[cfg(feature = "jiff")]
+        assert!(jiff::Timestamp::try_from(max).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_precision_conversion() {
+        use super::Precision;
+
+        let ts = Timestamp::Minutes(2)
+            .to_precision(Precision::Seconds)
+            .unwrap();
+        assert!(matches!(ts, Timestamp::Seconds(120)));
+        let ts = Timestamp::Seconds(120)
+            .to_precision(Precision::Minutes)
+            .unwrap();
+        assert!(matches!(ts, Timestamp::Minutes(2)));
+        assert!(Timestamp::Seconds(121)
+            .to_precision(Precision::Minutes)
+            .is_err());
+        assert!(matches!(
+            Timestamp::Seconds(121).truncate_to(Precision::Minutes),
+            Timestamp::Minutes(2)
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_duration_arithmetic() {
+        use std::time::Duration;
+
+        let ts = Timestamp::Seconds(10) + Duration::from_secs(5);
+        assert!(matches!(ts, Timestamp::Seconds(15)));
+        let ts = Timestamp::Seconds(10) + Duration::from_millis(1500);
+        assert!(matches!(ts, Timestamp::Milliseconds(11500)));
+        let ts = Timestamp::Milliseconds(10) - Duration::from_secs(0);
+        assert!(matches!(ts, Timestamp::Milliseconds(10)));
+        let ts = Timestamp::Hours(1) - Duration::from_secs(60);
+        assert!(matches!(ts, Timestamp::Minutes(59)));
+        assert!(Timestamp::Seconds(1)
+            .checked_sub(Duration::from_secs(2))
+            .is_none());
+    }
+
+    #[test]
+    fn test_timestamp_system_time() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let st = UNIX_EPOCH + Duration::from_millis(1500);
+        assert_eq!(
+            Timestamp::try_from(st).unwrap(),
+            Timestamp::Milliseconds(1500)
+        );
+        assert_eq!(
+            SystemTime::try_from(Timestamp::Milliseconds(1500)).unwrap(),
+            st
+        );
+        assert!(Timestamp::try_from(UNIX_EPOCH - Duration::from_secs(1)).is_err());
+        assert!(SystemTime::try_from(Timestamp::Hours(u64::MAX.into())).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_now() {
+        use super::Precision;
+
+        let now = Timestamp::now(Precision::Seconds);
+        assert_eq!(now.precision(), Precision::Seconds);
+        assert!(now > Timestamp::Hours(0));
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn test_chrono_datetime_from_timestamp_hours() {
@@ -355,6 +852,34 @@ mod tests {
         )
     }
 
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff_timestamp_from_timestamp() {
+        let ts: jiff::Timestamp = Timestamp::Minutes(2).try_into().unwrap();
+        assert_eq!(ts, jiff::Timestamp::from_second(120).unwrap());
+        assert!(jiff::Timestamp::try_from(Timestamp::Hours(u64::MAX.into())).is_err());
+
+        let zoned: jiff::Zoned = Timestamp::Seconds(1).try_into().unwrap();
+        assert_eq!(zoned.time_zone(), &jiff::tz::TimeZone::UTC);
+        assert_eq!(zoned.timestamp(), jiff::Timestamp::from_second(1).unwrap());
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_timestamp_from_jiff_timestamp() {
+        let ts: Timestamp = jiff::Timestamp::from_millisecond(1500)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(ts, Timestamp::Nanoseconds(1_500_000_000));
+        assert!(Timestamp::try_from(jiff::Timestamp::from_second(-1).unwrap()).is_err());
+
+        let zoned = jiff::Timestamp::from_second(1)
+            .unwrap()
+            .to_zoned(jiff::tz::TimeZone::fixed(jiff::tz::offset(2)));
+        assert_eq!(Timestamp::try_from(zoned).unwrap(), Timestamp::Seconds(1));
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn test_timestamp_from_chrono_date() {
@@ -0,0 +1,480 @@
+//! Borrowing alternative to [`WriteQuery`](crate::WriteQuery)
+//!
+//! A [`Point`] stores its measurement, keys and text values as [`Cow<str>`], so points built
+//! from `'static` names or from data that outlives the point don't allocate per key. Building
+//! the point escapes every term straight into the output buffer.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use influxdb::{Point, Query, Timestamp};
+//!
+//! let location = String::from("us-midwest");
+//! let point = Point::new(Timestamp::Hours(11), "weather")
+//!     .add_tag("location", &location)
+//!     .add_field("temperature", 82);
+//!
+//! assert_eq!(
+//!     point.build().unwrap(),
+//!     "weather,location=us-midwest temperature=82i 11"
+//! );
+//! ```
+
+use std::borrow::Cow;
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::query::line_proto_term::LineProtoTerm;
+use crate::query::write_query::saturating_nanos;
+use crate::query::{QueryType, ValidQuery};
+use crate::{Error, Query, Timestamp, Type, WriteQuery};
+
+/// Borrowing counterpart of [`Type`](crate::Type)
+#[derive(Debug, Clone, PartialEq)]
+pub enum PointValue<'a> {
+    Boolean(bool),
+    Float(f64),
+    SignedInteger(i64),
+    UnsignedInteger(u64),
+    Text(Cow<'a, str>),
+}
+
+impl PointValue<'_> {
+    /// Converts this value into an owned [`Type`](crate::Type)
+    pub fn into_type(self) -> Type {
+        match self {
+            PointValue::Boolean(x) => Type::Boolean(x),
+            PointValue::Float(x) => Type::Float(x),
+            PointValue::SignedInteger(x) => Type::SignedInteger(x),
+            PointValue::UnsignedInteger(x) => Type::UnsignedInteger(x),
+            PointValue::Text(x) => Type::Text(x.into_owned()),
+        }
+    }
+
+    /// Converts this value into a [`PointValue`] that doesn't borrow anything
+    pub fn into_owned(self) -> PointValue<'static> {
+        match self {
+            PointValue::Boolean(x) => PointValue::Boolean(x),
+            PointValue::Float(x) => PointValue::Float(x),
+            PointValue::SignedInteger(x) => PointValue::SignedInteger(x),
+            PointValue::UnsignedInteger(x) => PointValue::UnsignedInteger(x),
+            PointValue::Text(x) => PointValue::Text(Cow::Owned(x.into_owned())),
+        }
+    }
+}
+
+macro_rules! from_impl {
+        ( $( $typ:ident ),+ ) => (
+                $(
+                    impl From<$typ> for PointValue<'_> {
+                        fn from(b: $typ) -> Self {
+                            Type::from(b).into()
+                        }
+                    }
+
+                    impl From<&$typ> for PointValue<'_> {
+                        fn from(b: &$typ) -> Self {
+                            Type::from(*b).into()
+                        }
+                    }
+                )+
+        )
+}
+from_impl! {bool}
+from_impl! {f32, f64}
+from_impl! {i8, i16, i32, i64}
+from_impl! {u8, u16, u32, u64}
+from_impl! {isize, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize}
+from_impl! {usize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize}
+from_impl! {char}
+
+impl From<Duration> for PointValue<'_> {
+    fn from(d: Duration) -> Self {
+        PointValue::SignedInteger(saturating_nanos(d))
+    }
+}
+
+impl From<&Duration> for PointValue<'_> {
+    fn from(d: &Duration) -> Self {
+        PointValue::SignedInteger(saturating_nanos(*d))
+    }
+}
+
+impl<'a> From<&'a str> for PointValue<'a> {
+    fn from(b: &'a str) -> Self {
+        PointValue::Text(Cow::Borrowed(b))
+    }
+}
+
+impl<'a> From<&'a &str> for PointValue<'a> {
+    fn from(b: &'a &str) -> Self {
+        PointValue::Text(Cow::Borrowed(b))
+    }
+}
+
+impl<'a> From<&'a String> for PointValue<'a> {
+    fn from(b: &'a String) -> Self {
+        PointValue::Text(Cow::Borrowed(b))
+    }
+}
+
+impl From<String> for PointValue<'_> {
+    fn from(b: String) -> Self {
+        PointValue::Text(Cow::Owned(b))
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for PointValue<'a> {
+    fn from(b: Cow<'a, str>) -> Self {
+        PointValue::Text(b)
+    }
+}
+
+impl<'a> From<&'a Cow<'_, str>> for PointValue<'a> {
+    fn from(b: &'a Cow<'_, str>) -> Self {
+        PointValue::Text(Cow::Borrowed(b))
+    }
+}
+
+impl<'a> From<&'a Arc<str>> for PointValue<'a> {
+    fn from(b: &'a Arc<str>) -> Self {
+        PointValue::Text(Cow::Borrowed(b))
+    }
+}
+
+impl<'a> From<&'a Box<str>> for PointValue<'a> {
+    fn from(b: &'a Box<str>) -> Self {
+        PointValue::Text(Cow::Borrowed(b))
+    }
+}
+
+impl<'a> From<&'a Type> for PointValue<'a> {
+    fn from(t: &'a Type) -> Self {
+        match t {
+            Type::Boolean(x) => PointValue::Boolean(*x),
+            Type::Float(x) => PointValue::Float(*x),
+            Type::SignedInteger(x) => PointValue::SignedInteger(*x),
+            Type::UnsignedInteger(x) => PointValue::UnsignedInteger(*x),
+            Type::Text(x) => PointValue::Text(Cow::Borrowed(x)),
+        }
+    }
+}
+
+impl From<Type> for PointValue<'_> {
+    fn from(t: Type) -> Self {
+        match t {
+            Type::Text(x) => PointValue::Text(Cow::Owned(x)),
+            t => PointValue::from(&t).into_owned(),
+        }
+    }
+}
+
+/// A value that can be added to a [`Point`] as a tag or field: anything that converts into a
+/// [`PointValue`], and options of those, which are skipped if they are `None`
+pub trait PointWriteType<'a> {
+    fn add_to(self, key: Cow<'a, str>, fields_or_tags: &mut Vec<(Cow<'a, str>, PointValue<'a>)>);
+}
+
+impl<'a, T: Into<PointValue<'a>>> PointWriteType<'a> for T {
+    fn add_to(self, key: Cow<'a, str>, fields_or_tags: &mut Vec<(Cow<'a, str>, PointValue<'a>)>) {
+        fields_or_tags.push((key, self.into()));
+    }
+}
+
+impl<'a, T: Into<PointValue<'a>>> PointWriteType<'a> for Option<T> {
+    fn add_to(self, key: Cow<'a, str>, fields_or_tags: &mut Vec<(Cow<'a, str>, PointValue<'a>)>) {
+        if let Some(val) = self {
+            val.add_to(key, fields_or_tags);
+        }
+    }
+}
+
+impl<'a, T> PointWriteType<'a> for &'a Option<T>
+where
+    &'a T: Into<PointValue<'a>>,
+{
+    fn add_to(self, key: Cow<'a, str>, fields_or_tags: &mut Vec<(Cow<'a, str>, PointValue<'a>)>) {
+        self.as_ref().add_to(key, fields_or_tags);
+    }
+}
+
+/// A single point in line protocol that borrows its measurement, keys and values where possible
+#[derive(Debug, Clone)]
+pub struct Point<'a> {
+    fields: Vec<(Cow<'a, str>, PointValue<'a>)>,
+    tags: Vec<(Cow<'a, str>, PointValue<'a>)>,
+    measurement: Cow<'a, str>,
+    timestamp: Timestamp,
+}
+
+impl<'a> Point<'a> {
+    /// Creates a new [`Point`]
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn new<S>(timestamp: Timestamp, measurement: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Point {
+            fields: vec![],
+            tags: vec![],
+            measurement: measurement.into(),
+            timestamp,
+        }
+    }
+
+    /// Adds a field to the [`Point`]
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn add_field<S, F>(mut self, field: S, value: F) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        F: PointWriteType<'a>,
+    {
+        value.add_to(field.into(), &mut self.fields);
+        self
+    }
+
+    /// Adds a tag to the [`Point`]
+    ///
+    /// Please note that a [`Point`] requires at least one field. Composing a point with
+    /// only tags will result in a failure building the query.
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn add_tag<S, I>(mut self, tag: S, value: I) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        I: PointWriteType<'a>,
+    {
+        value.add_to(tag.into(), &mut self.tags);
+        self
+    }
+
+    pub fn get_precision(&self) -> String {
+        self.timestamp.precision().to_string()
+    }
+
+    /// Converts this point into one that doesn't borrow anything
+    pub fn into_owned(self) -> Point<'static> {
+        let into_owned = |(key, value): (Cow<'_, str>, PointValue<'_>)| {
+            (Cow::Owned(key.into_owned()), value.into_owned())
+        };
+        Point {
+            fields: self.fields.into_iter().map(into_owned).collect(),
+            tags: self.tags.into_iter().map(into_owned).collect(),
+            measurement: Cow::Owned(self.measurement.into_owned()),
+            timestamp: self.timestamp,
+        }
+    }
+
+    /// Appends the line protocol representation of this point to `buf`
+    pub(crate) fn write_to(&self, buf: &mut String, use_v2: bool) -> Result<(), Error> {
+        if self.fields.is_empty() {
+            return Err(Error::InvalidQueryError {
+                error: "fields cannot be empty".to_string(),
+            });
+        }
+
+        LineProtoTerm::Measurement(&self.measurement).escape_into(buf, use_v2);
+        for (tag, value) in &self.tags {
+            buf.push(',');
+            LineProtoTerm::TagKey(tag).escape_into(buf, use_v2);
+            buf.push('=');
+            LineProtoTerm::TagValue(value).escape_into(buf, use_v2);
+        }
+        for (i, (field, value)) in self.fields.iter().enumerate() {
+            buf.push(if i == 0 { ' ' } else { ',' });
+            LineProtoTerm::FieldKey(field).escape_into(buf, use_v2);
+            buf.push('=');
+            LineProtoTerm::FieldValue(value).escape_into(buf, use_v2);
+        }
+        buf.push(' ');
+        buf.push_str(&self.timestamp.to_string());
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a WriteQuery> for Point<'a> {
+    fn from(query: &'a WriteQuery) -> Self {
+        let borrow = |(key, value): &'a (String, Type)| (Cow::Borrowed(key.as_str()), value.into());
+        Point {
+            fields: query.fields.iter().map(borrow).collect(),
+            tags: query.tags.iter().map(borrow).collect(),
+            measurement: Cow::Borrowed(&query.measurement),
+            timestamp: query.timestamp,
+        }
+    }
+}
+
+impl From<Point<'_>> for WriteQuery {
+    fn from(point: Point<'_>) -> Self {
+        let into_owned =
+            |(key, value): (Cow<'_, str>, PointValue<'_>)| (key.into_owned(), value.into_type());
+        WriteQuery {
+            fields: point.fields.into_iter().map(into_owned).collect(),
+            tags: point.tags.into_iter().map(into_owned).collect(),
+            measurement: point.measurement.into_owned(),
+            timestamp: point.timestamp,
+        }
+    }
+}
+
+impl Query for Point<'_> {
+    fn build(&self) -> Result<ValidQuery, Error> {
+        self.build_with_opts(false)
+    }
+
+    fn build_with_opts(&self, use_v2: bool) -> Result<ValidQuery, Error> {
+        let mut buf = String::new();
+        self.write_to(&mut buf, use_v2)?;
+        Ok(ValidQuery(buf))
+    }
+
+    fn get_type(&self) -> QueryType {
+        QueryType::WriteQuery(self.get_precision())
+    }
+}
+
+impl Query for Vec<Point<'_>> {
+    fn build(&self) -> Result<ValidQuery, Error> {
+        self.build_with_opts(false)
+    }
+
+    fn build_with_opts(&self, use_v2: bool) -> Result<ValidQuery, Error> {
+        let mut buf = String::new();
+        for (i, point) in self.iter().enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+            point.write_to(&mut buf, use_v2)?;
+        }
+        Ok(ValidQuery(buf))
+    }
+
+    fn get_type(&self) -> QueryType {
+        QueryType::WriteQuery(
+            self.first()
+                .map(|p| p.get_precision())
+                // use "ms" as placeholder if query is empty
+                .unwrap_or_else(|| "ms".to_owned()),
+        )
+    }
+}
+
+/// Types that can be converted into a [`Point`] borrowing from `self`
+///
+/// This is implemented by `#[derive(InfluxDbWriteable)]`, and allows writing a value
+/// without giving up ownership or cloning its strings.
+pub trait ToPoint {
+    type Error;
+
+    fn to_point<'a, I: Into<Cow<'a, str>>>(&'a self, name: I) -> Result<Point<'a>, Self::Error>;
+}
+
+impl ToPoint for Timestamp {
+    type Error = std::convert::Infallible;
+
+    fn to_point<'a, I: Into<Cow<'a, str>>>(&'a self, name: I) -> Result<Point<'a>, Self::Error> {
+        Ok(Point::new(*self, name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{Point, PointValue};
+    use crate::{InfluxDbWriteable, Query, Timestamp, Type, WriteQuery};
+
+    #[test]
+    fn test_point_borrows_strings() {
+        let location = String::from("us-midwest");
+        let point = Point::new(Timestamp::Hours(11), "weather")
+            .add_tag("location", &location)
+            .add_field("temperature", 82)
+            .add_field("season", "summer");
+
+        assert!(matches!(
+            point.tags[0].1,
+            PointValue::Text(Cow::Borrowed(_))
+        ));
+        assert!(matches!(
+            point.fields[1].1,
+            PointValue::Text(Cow::Borrowed(_))
+        ));
+        assert_eq!(
+            point.build().unwrap(),
+            r#"weather,location=us-midwest temperature=82i,season="summer" 11"#
+        );
+    }
+
+    #[test]
+    fn test_point_optional_values() {
+        let wind_direction: Option<String> = None;
+        let season = Some(String::from("summer"));
+        let point = Point::new(Timestamp::Hours(11), "weather")
+            .add_tag("wind_direction", &wind_direction)
+            .add_tag("season", &season)
+            .add_field("pressure", Some(100u64));
+
+        assert!(matches!(
+            point.tags[0].1,
+            PointValue::Text(Cow::Borrowed(_))
+        ));
+        assert_eq!(
+            point.build_with_opts(true).unwrap(),
+            "weather,season=summer pressure=100u 11"
+        );
+        assert!(wind_direction.is_none() && season.is_some());
+    }
+
+    #[test]
+    fn test_point_empty_fields() {
+        let point = Point::new(Timestamp::Hours(11), "weather").add_tag("season", "summer");
+
+        assert!(point.build().is_err(), "Point missing one or more fields");
+    }
+
+    #[test]
+    fn test_point_matches_write_query() {
+        let query = Timestamp::Hours(11)
+            .try_into_query("wea, ther=")
+            .unwrap()
+            .add_field("temperature", 82)
+            .add_field("\"temp=era,t ure\"", r#"too"\\hot"#)
+            .add_tag("loc, =\"ation", r#"us, "mid=west"#);
+        let point = Point::from(&query);
+
+        assert_eq!(point.build().unwrap().get(), query.build().unwrap().get());
+        assert_eq!(
+            WriteQuery::from(point.into_owned()).build().unwrap().get(),
+            query.build().unwrap().get()
+        );
+    }
+
+    #[test]
+    fn test_point_batch() {
+        let points = vec![
+            Point::new(Timestamp::Hours(11), "weather").add_field("temperature", 82),
+            Point::new(Timestamp::Hours(12), "weather").add_field("temperature", 65),
+        ];
+
+        assert_eq!(
+            points.build().unwrap().get(),
+            "weather temperature=82i 11\nweather temperature=65i 12"
+        );
+    }
+
+    #[test]
+    fn test_point_value_from_type() {
+        assert_eq!(
+            PointValue::from(&Type::Text("a".into())),
+            PointValue::Text(Cow::Borrowed("a"))
+        );
+        assert_eq!(
+            PointValue::from(Type::UnsignedInteger(1)),
+            PointValue::UnsignedInteger(1)
+        );
+    }
+}
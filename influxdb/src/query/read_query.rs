@@ -1,11 +1,12 @@
 //! Read Query Builder
 
 use crate::query::{QueryType, ValidQuery};
-use crate::{Error, Query};
+use crate::{Error, Precision, Query};
 
 #[derive(Debug, Clone)]
 pub struct ReadQuery {
     queries: Vec<String>,
+    epoch: Option<Precision>,
 }
 
 impl ReadQuery {
@@ -17,6 +18,7 @@ impl ReadQuery {
     {
         ReadQuery {
             queries: vec![query.into()],
+            epoch: None,
         }
     }
 
@@ -29,6 +31,14 @@ impl ReadQuery {
         self.queries.push(query.into());
         self
     }
+
+    /// Returns times as integers counted in `precision` instead of RFC3339 strings, see
+    /// [`timestamp`](crate::integrations::serde_integration::timestamp) to deserialize them
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn with_epoch(mut self, precision: Precision) -> Self {
+        self.epoch = Some(precision);
+        self
+    }
 }
 
 impl Query for ReadQuery {
@@ -43,12 +53,17 @@ impl Query for ReadQuery {
     fn get_type(&self) -> QueryType {
         QueryType::ReadQuery
     }
+
+    fn get_epoch(&self) -> Option<Precision> {
+        self.epoch
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::ReadQuery;
     use crate::query::{Query, QueryType};
+    use crate::Precision;
 
     #[test]
     fn test_read_builder_single_query() {
@@ -72,4 +87,15 @@ mod tests {
 
         assert_eq!(query.get_type(), QueryType::ReadQuery);
     }
+
+    #[test]
+    fn test_epoch() {
+        assert_eq!(ReadQuery::new("SELECT * FROM aachen").get_epoch(), None);
+        assert_eq!(
+            ReadQuery::new("SELECT * FROM aachen")
+                .with_epoch(Precision::Milliseconds)
+                .get_epoch(),
+            Some(Precision::Milliseconds)
+        );
+    }
 }
@@ -0,0 +1,255 @@
+//! Typed `SELECT` queries
+//!
+//! A [`SelectQuery`] selects the tags and fields of a type from a measurement. It is usually
+//! created by the `select` and `select_from` functions generated by
+//! `#[derive(InfluxDbWriteable)]` for structs marked with `#[influxdb(select)]`, which also add
+//! a `where_<field>` method for every tag and field of the struct.
+
+use std::fmt::{self, Debug, Formatter, Write as _};
+use std::marker::PhantomData;
+
+use crate::query::{QueryType, ValidQuery};
+use crate::{Error, Precision, Query, ReadQuery, Timestamp, Type};
+
+/// A `SELECT` query for the tags and fields of `T`
+pub struct SelectQuery<T> {
+    measurement: String,
+    columns: Vec<String>,
+    conditions: Vec<String>,
+    descending: bool,
+    limit: Option<u64>,
+    epoch: Option<Precision>,
+    /// An invalid condition, returned when building the query
+    error: Option<String>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> SelectQuery<T> {
+    /// Creates a new [`SelectQuery`] selecting `columns` from `measurement`
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn new<S, I, C>(measurement: S, columns: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = C>,
+        C: Into<String>,
+    {
+        SelectQuery {
+            measurement: measurement.into(),
+            columns: columns.into_iter().map(Into::into).collect(),
+            conditions: Vec::new(),
+            descending: false,
+            limit: None,
+            epoch: None,
+            error: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Only selects points where the tag `key` equals `value`
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn where_tag<V: Into<Type>>(mut self, key: &str, value: V) -> Self {
+        // Tag values are always strings
+        let value = value.into().to_string();
+        self.conditions.push(format!(
+            "{} = {}",
+            quote_identifier(key),
+            quote_string(&value)
+        ));
+        self
+    }
+
+    /// Only selects points where the field `key` equals `value`
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn where_field<V: Into<Type>>(mut self, key: &str, value: V) -> Self {
+        let value = match value.into() {
+            Type::Text(text) => quote_string(&text),
+            value => value.to_string(),
+        };
+        self.conditions
+            .push(format!("{} = {value}", quote_identifier(key)));
+        self
+    }
+
+    /// Only selects points with a timestamp in `start..end`
+    ///
+    /// Building the query fails if the nanoseconds of `start` or `end` overflow a `u128`.
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn between(mut self, start: Timestamp, end: Timestamp) -> Self {
+        match (start.checked_nanos(), end.checked_nanos()) {
+            (Some(start), Some(end)) => self
+                .conditions
+                .push(format!("time >= {start} AND time < {end}")),
+            _ => {
+                self.error.get_or_insert_with(|| {
+                    format!("timestamps {start:?} and {end:?} are too large")
+                });
+            }
+        }
+        self
+    }
+
+    /// Returns the most recent points first
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn descending(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+
+    /// Returns at most `limit` points per series
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns times as integers counted in `precision`, see [`ReadQuery::with_epoch`]
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn with_epoch(mut self, precision: Precision) -> Self {
+        self.epoch = Some(precision);
+        self
+    }
+
+    fn to_influxql(&self) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| quote_identifier(column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut query = format!(
+            "SELECT {columns} FROM {}",
+            quote_identifier(&self.measurement)
+        );
+        if !self.conditions.is_empty() {
+            write!(query, " WHERE {}", self.conditions.join(" AND ")).unwrap();
+        }
+        if self.descending {
+            query.push_str(" ORDER BY time DESC");
+        }
+        if let Some(limit) = self.limit {
+            write!(query, " LIMIT {limit}").unwrap();
+        }
+        query
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: crate::InfluxDbReadable> SelectQuery<T> {
+    /// Runs the query and reads all returned rows into `T`
+    pub async fn fetch(self, client: &crate::Client) -> Result<Vec<T>, Error> {
+        client.json_query(self).await?.read_next()
+    }
+}
+
+impl<T> Clone for SelectQuery<T> {
+    fn clone(&self) -> Self {
+        SelectQuery {
+            measurement: self.measurement.clone(),
+            columns: self.columns.clone(),
+            conditions: self.conditions.clone(),
+            descending: self.descending,
+            limit: self.limit,
+            epoch: self.epoch,
+            error: self.error.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for SelectQuery<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SelectQuery")
+            .field(&self.to_influxql())
+            .finish()
+    }
+}
+
+/// Fails like [`Query::build`] if the query is invalid
+impl<T> TryFrom<SelectQuery<T>> for ReadQuery {
+    type Error = Error;
+
+    fn try_from(query: SelectQuery<T>) -> Result<Self, Self::Error> {
+        let read_query = ReadQuery::new(query.build()?.0);
+        Ok(match query.epoch {
+            Some(epoch) => read_query.with_epoch(epoch),
+            None => read_query,
+        })
+    }
+}
+
+impl<T> Query for SelectQuery<T> {
+    fn build(&self) -> Result<ValidQuery, Error> {
+        self.build_with_opts(false)
+    }
+
+    fn build_with_opts(&self, _use_v2: bool) -> Result<ValidQuery, Error> {
+        if let Some(error) = &self.error {
+            return Err(Error::InvalidQueryError {
+                error: error.clone(),
+            });
+        }
+        if self.columns.is_empty() {
+            return Err(Error::InvalidQueryError {
+                error: "columns cannot be empty".to_string(),
+            });
+        }
+        Ok(ValidQuery(self.to_influxql()))
+    }
+
+    fn get_type(&self) -> QueryType {
+        QueryType::ReadQuery
+    }
+
+    fn get_epoch(&self) -> Option<Precision> {
+        self.epoch
+    }
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!(
+        "\"{}\"",
+        identifier.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SelectQuery;
+    use crate::{Query, Timestamp};
+
+    #[test]
+    fn test_select_query() {
+        let query = SelectQuery::<()>::new("weather", ["temperature", "wind direction"])
+            .where_tag("location", "it's")
+            .where_field("temperature", 21)
+            .where_field("note", "a")
+            .between(Timestamp::Seconds(1), Timestamp::Seconds(2))
+            .descending()
+            .limit(10);
+
+        assert_eq!(
+            query.build().unwrap(),
+            "SELECT \"temperature\", \"wind direction\" FROM \"weather\" \
+             WHERE \"location\" = 'it\\'s' AND \"temperature\" = 21 AND \"note\" = 'a' \
+             AND time >= 1000000000 AND time < 2000000000 ORDER BY time DESC LIMIT 10"
+        );
+    }
+
+    #[test]
+    fn test_select_query_overflowing_timestamps() {
+        let query = SelectQuery::<()>::new("weather", ["temperature"])
+            .between(Timestamp::Seconds(1), Timestamp::Hours(u128::MAX));
+        assert!(query.build().is_err());
+        assert!(crate::ReadQuery::try_from(query).is_err());
+    }
+
+    #[test]
+    fn test_select_query_without_columns() {
+        let query = SelectQuery::<()>::new("weather", Vec::<String>::new());
+        assert!(query.build().is_err());
+    }
+}
@@ -2,10 +2,17 @@
 //!
 //! Can only be instantiated by using Query::write_query
 
-use crate::query::line_proto_term::LineProtoTerm;
 use crate::query::{QueryType, ValidQuery};
-use crate::{Error, Query, Timestamp};
+use crate::{Error, Point, Query, Timestamp};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize, TryFromIntError,
+};
+use std::sync::Arc;
+use std::time::Duration;
 
 pub trait WriteType {
     fn add_to(self, tag: String, fields_or_tags: &mut Vec<(String, Type)>);
@@ -26,13 +33,34 @@ impl<T: Into<Type>> WriteType for Option<T> {
     }
 }
 
+/// Writes the duration as integer nanoseconds, saturating at `i64::MAX` (roughly 292 years).
+/// Use `Type::try_from` to fail for longer durations instead.
+impl WriteType for Duration {
+    fn add_to(self, tag: String, fields_or_tags: &mut Vec<(String, Type)>) {
+        fields_or_tags.push((tag, Type::SignedInteger(saturating_nanos(self))));
+    }
+}
+
+impl WriteType for Option<Duration> {
+    fn add_to(self, tag: String, fields_or_tags: &mut Vec<(String, Type)>) {
+        if let Some(val) = self {
+            val.add_to(tag, fields_or_tags);
+        }
+    }
+}
+
+/// The nanoseconds of `d`, or `i64::MAX` if they don't fit
+pub(crate) fn saturating_nanos(d: Duration) -> i64 {
+    d.as_nanos().try_into().unwrap_or(i64::MAX)
+}
+
 /// Internal Representation of a Write query that has not yet been built
 #[derive(Debug, Clone)]
 pub struct WriteQuery {
-    fields: Vec<(String, Type)>,
-    tags: Vec<(String, Type)>,
-    measurement: String,
-    timestamp: Timestamp,
+    pub(crate) fields: Vec<(String, Type)>,
+    pub(crate) tags: Vec<(String, Type)>,
+    pub(crate) measurement: String,
+    pub(crate) timestamp: Timestamp,
 }
 
 impl WriteQuery {
@@ -99,18 +127,177 @@ impl WriteQuery {
     }
 
     pub fn get_precision(&self) -> String {
-        let modifier = match self.timestamp {
-            Timestamp::Nanoseconds(_) => "ns",
-            Timestamp::Microseconds(_) => "u",
-            Timestamp::Milliseconds(_) => "ms",
-            Timestamp::Seconds(_) => "s",
-            Timestamp::Minutes(_) => "m",
-            Timestamp::Hours(_) => "h",
-        };
-        modifier.to_string()
+        self.timestamp.precision().to_string()
+    }
+
+    /// Sorts the tags of this [`WriteQuery`](crate::WriteQuery) lexicographically by key
+    ///
+    /// InfluxDB recommends sending tags in this order, as it avoids sorting them on ingest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use influxdb::{InfluxDbWriteable, Query, Timestamp};
+    ///
+    /// let query = Timestamp::Nanoseconds(0)
+    ///     .try_into_query("weather")
+    ///     .unwrap()
+    ///     .add_tag("season", "summer")
+    ///     .add_tag("location", "us-midwest")
+    ///     .add_field("temperature", 82)
+    ///     .sort_tags();
+    ///
+    /// assert_eq!(
+    ///     query.build().unwrap(),
+    ///     "weather,location=us-midwest,season=summer temperature=82i 0"
+    /// );
+    /// ```
+    #[must_use = "Creating a query is pointless unless you execute it"]
+    pub fn sort_tags(mut self) -> Self {
+        self.tags.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self
+    }
+
+    /// Returns the [`SeriesKey`] identifying the series this point belongs to
+    pub fn series_key(&self) -> SeriesKey {
+        let mut tags = self
+            .tags
+            .iter()
+            .map(|(key, value)| (key.clone(), value.to_string()))
+            .collect::<Vec<_>>();
+        tags.sort();
+        SeriesKey {
+            measurement: self.measurement.clone(),
+            tags,
+        }
+    }
+
+    /// Sorts the tags of every point and merges points that share measurement, tags and
+    /// timestamp into a single point
+    ///
+    /// The merged point takes the position of the first occurrence in the batch. Fields
+    /// present in more than one of the merged points are resolved according to `policy`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use influxdb::{DuplicatePolicy, InfluxDbWriteable, Query, Timestamp, WriteQuery};
+    ///
+    /// let batch = vec![
+    ///     Timestamp::Seconds(1)
+    ///         .try_into_query("weather")
+    ///         .unwrap()
+    ///         .add_tag("location", "berlin")
+    ///         .add_field("temperature", 20),
+    ///     Timestamp::Seconds(1)
+    ///         .try_into_query("weather")
+    ///         .unwrap()
+    ///         .add_tag("location", "berlin")
+    ///         .add_field("humidity", 30),
+    /// ];
+    ///
+    /// let batch = WriteQuery::canonicalize(batch, DuplicatePolicy::KeepLast).unwrap();
+    /// assert_eq!(
+    ///     batch.build().unwrap(),
+    ///     "weather,location=berlin temperature=20i,humidity=30i 1"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidQueryError`] if two merged points set the same field and
+    /// `policy` is [`DuplicatePolicy::Error`].
+    pub fn canonicalize<I>(queries: I, policy: DuplicatePolicy) -> Result<Vec<WriteQuery>, Error>
+    where
+        I: IntoIterator<Item = WriteQuery>,
+    {
+        let mut canonical: Vec<WriteQuery> = Vec::new();
+        let mut positions: HashMap<(SeriesKey, Timestamp), usize> = HashMap::new();
+
+        for query in queries {
+            let query = query.sort_tags();
+            let key = (query.series_key(), query.timestamp);
+            match positions.get(&key) {
+                Some(&idx) => canonical[idx].merge_fields(query.fields, policy)?,
+                None => {
+                    positions.insert(key, canonical.len());
+                    canonical.push(query);
+                }
+            }
+        }
+
+        Ok(canonical)
+    }
+
+    fn merge_fields(
+        &mut self,
+        fields: Vec<(String, Type)>,
+        policy: DuplicatePolicy,
+    ) -> Result<(), Error> {
+        for (field, value) in fields {
+            match self
+                .fields
+                .iter_mut()
+                .find(|(existing, _)| *existing == field)
+            {
+                Some((_, existing)) => match policy {
+                    DuplicatePolicy::KeepFirst => {}
+                    DuplicatePolicy::KeepLast => *existing = value,
+                    DuplicatePolicy::Error => {
+                        return Err(Error::InvalidQueryError {
+                            error: format!(
+                                "duplicate field {field:?} for point in measurement {:?}",
+                                self.measurement
+                            ),
+                        })
+                    }
+                },
+                None => self.fields.push((field, value)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Identifies a series by its measurement and its tag set, independent of tag order
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SeriesKey {
+    measurement: String,
+    tags: Vec<(String, String)>,
+}
+
+impl SeriesKey {
+    /// Returns the measurement of the series
+    pub fn measurement(&self) -> &str {
+        &self.measurement
+    }
+
+    /// Returns the tags of the series, sorted by key
+    pub fn tags(&self) -> &[(String, String)] {
+        &self.tags
     }
 }
 
+/// Decides which value is kept when points merged by
+/// [`WriteQuery::canonicalize`](crate::WriteQuery::canonicalize) set the same field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePolicy {
+    /// Keep the value of the point that appeared first in the batch
+    KeepFirst,
+    /// Keep the value of the point that appeared last in the batch, like InfluxDB does
+    #[default]
+    KeepLast,
+    /// Fail with an [`Error::InvalidQueryError`](crate::Error::InvalidQueryError)
+    Error,
+}
+
+/// A tag or field value
+///
+/// Values that don't always fit into a variant, like `i128`, `u128` and
+/// `bigdecimal::BigDecimal`, only implement `TryFrom`. They can't be passed to
+/// [`WriteQuery::add_field`] or used as derived fields directly; convert them with
+/// `Type::try_from` first, or with `#[influxdb(serialize_with = "...")]` on derived fields.
+/// [`Duration`]s can be passed directly and are written as saturating integer nanoseconds.
 #[derive(Debug, Clone)]
 pub enum Type {
     Boolean(bool),
@@ -156,6 +343,153 @@ impl From<&str> for Type {
     }
 }
 
+macro_rules! from_as_impl {
+        ( $variant:ident($target:ty) => $( $typ:ident ),+ ) => (
+                $(
+                    impl From<$typ> for Type {
+                        fn from(b: $typ) -> Self {
+                            // usize and isize are at most 64 bits wide on all supported targets
+                            Type::$variant(b as $target)
+                        }
+                    }
+                )+
+        )
+}
+from_as_impl! {SignedInteger(i64) => isize}
+from_as_impl! {UnsignedInteger(u64) => usize}
+
+macro_rules! from_nonzero_impl {
+        ( $( $typ:ident ),+ ) => (
+                $(
+                    impl From<$typ> for Type {
+                        fn from(b: $typ) -> Self {
+                            b.get().into()
+                        }
+                    }
+                )+
+        )
+}
+from_nonzero_impl! {NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize}
+from_nonzero_impl! {NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize}
+
+impl From<char> for Type {
+    fn from(b: char) -> Self {
+        Type::Text(b.into())
+    }
+}
+
+impl From<Cow<'_, str>> for Type {
+    fn from(b: Cow<'_, str>) -> Self {
+        Type::Text(b.into_owned())
+    }
+}
+
+impl From<Arc<str>> for Type {
+    fn from(b: Arc<str>) -> Self {
+        Type::Text(b.as_ref().into())
+    }
+}
+
+impl From<Box<str>> for Type {
+    fn from(b: Box<str>) -> Self {
+        Type::Text(b.into())
+    }
+}
+
+impl TryFrom<i128> for Type {
+    type Error = TryFromIntError;
+
+    fn try_from(b: i128) -> Result<Self, Self::Error> {
+        Ok(Type::SignedInteger(b.try_into()?))
+    }
+}
+
+impl TryFrom<u128> for Type {
+    type Error = TryFromIntError;
+
+    fn try_from(b: u128) -> Result<Self, Self::Error> {
+        Ok(Type::UnsignedInteger(b.try_into()?))
+    }
+}
+
+/// Converts the duration into an integer number of nanoseconds, failing if it
+/// doesn't fit into an `i64` (roughly 292 years).
+impl TryFrom<Duration> for Type {
+    type Error = TryFromIntError;
+
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        Ok(Type::SignedInteger(d.as_nanos().try_into()?))
+    }
+}
+
+/// Decides how decimal numbers are written, as InfluxDB has no decimal type
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalPolicy {
+    /// Write the closest `f64`, possibly losing precision
+    #[default]
+    Float,
+    /// Write the exact decimal representation as a string
+    Text,
+}
+
+#[cfg(feature = "bigdecimal")]
+impl DecimalPolicy {
+    fn convert<D: Display>(self, value: D) -> Result<Type, crate::DecimalRangeError> {
+        let text = value.to_string();
+        match self {
+            DecimalPolicy::Float => match text.parse::<f64>() {
+                Ok(float) if float.is_finite() => Ok(Type::Float(float)),
+                _ => Err(crate::DecimalRangeError(())),
+            },
+            DecimalPolicy::Text => Ok(Type::Text(text)),
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Type {
+    /// Converts a [`rust_decimal::Decimal`] according to `policy`
+    pub fn from_rust_decimal(value: rust_decimal::Decimal, policy: DecimalPolicy) -> Self {
+        match policy {
+            DecimalPolicy::Float => Type::Float(value.as_f64()),
+            DecimalPolicy::Text => Type::Text(value.to_string()),
+        }
+    }
+}
+
+/// Converts the decimal into the closest `f64`. Use [`Type::from_rust_decimal`] to
+/// write it as a string instead.
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Type {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Type::from_rust_decimal(value, DecimalPolicy::Float)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl Type {
+    /// Converts a [`bigdecimal::BigDecimal`] according to `policy`, failing if it is written as
+    /// a float and out of the range of `f64`
+    pub fn from_bigdecimal(
+        value: &bigdecimal::BigDecimal,
+        policy: DecimalPolicy,
+    ) -> Result<Self, crate::DecimalRangeError> {
+        policy.convert(value)
+    }
+}
+
+/// Converts the decimal into the closest `f64`, failing if it is out of the range of `f64`.
+/// Use [`Type::from_bigdecimal`] to write it as a string instead.
+#[cfg(feature = "bigdecimal")]
+impl TryFrom<bigdecimal::BigDecimal> for Type {
+    type Error = crate::DecimalRangeError;
+
+    fn try_from(value: bigdecimal::BigDecimal) -> Result<Self, Self::Error> {
+        Type::from_bigdecimal(&value, DecimalPolicy::Float)
+    }
+}
+
 #[cfg(feature = "chrono")]
 impl TryFrom<chrono::DateTime<chrono::Utc>> for Type {
     type Error = crate::error::TimestampTooLargeError;
@@ -178,6 +512,24 @@ impl TryFrom<time::UtcDateTime> for Type {
     }
 }
 
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::Timestamp> for Type {
+    type Error = <i64 as TryFrom<i128>>::Error;
+
+    fn try_from(ts: jiff::Timestamp) -> Result<Self, Self::Error> {
+        Ok(Self::SignedInteger(ts.as_nanosecond().try_into()?))
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::Zoned> for Type {
+    type Error = <i64 as TryFrom<i128>>::Error;
+
+    fn try_from(zoned: jiff::Zoned) -> Result<Self, Self::Error> {
+        zoned.timestamp().try_into()
+    }
+}
+
 impl<T> From<&T> for Type
 where
     T: Copy + Into<Type>,
@@ -193,66 +545,7 @@ impl Query for WriteQuery {
     }
 
     fn build_with_opts(&self, use_v2: bool) -> Result<ValidQuery, Error> {
-        if self.fields.is_empty() {
-            return Err(Error::InvalidQueryError {
-                error: "fields cannot be empty".to_string(),
-            });
-        }
-
-        let mut tags = self
-            .tags
-            .iter()
-            .map(|(tag, value)| {
-                let escaped_tag_key = if use_v2 {
-                    LineProtoTerm::TagKey(tag).escape_v2()
-                } else {
-                    LineProtoTerm::TagKey(tag).escape()
-                };
-                let escaped_tag_value = if use_v2 {
-                    LineProtoTerm::TagValue(value).escape_v2()
-                } else {
-                    LineProtoTerm::TagValue(value).escape()
-                };
-                format!("{escaped_tag_key}={escaped_tag_value}")
-            })
-            .collect::<Vec<String>>()
-            .join(",");
-
-        if !tags.is_empty() {
-            tags.insert(0, ',');
-        }
-        let fields = self
-            .fields
-            .iter()
-            .map(|(field, value)| {
-                let escaped_field_key = if use_v2 {
-                    LineProtoTerm::FieldKey(field).escape_v2()
-                } else {
-                    LineProtoTerm::FieldKey(field).escape()
-                };
-                let escaped_field_value = if use_v2 {
-                    LineProtoTerm::FieldValue(value).escape_v2()
-                } else {
-                    LineProtoTerm::FieldValue(value).escape()
-                };
-                format!("{escaped_field_key}={escaped_field_value}")
-            })
-            .collect::<Vec<String>>()
-            .join(",");
-
-        let escaped_measurement = if use_v2 {
-            LineProtoTerm::Measurement(&self.measurement).escape_v2()
-        } else {
-            LineProtoTerm::Measurement(&self.measurement).escape()
-        };
-
-        Ok(ValidQuery(format!(
-            "{measurement}{tags} {fields} {time}",
-            measurement = escaped_measurement,
-            tags = tags,
-            fields = fields,
-            time = self.timestamp
-        )))
+        Point::from(self).build_with_opts(use_v2)
     }
 
     fn get_type(&self) -> QueryType {
@@ -262,25 +555,18 @@ impl Query for WriteQuery {
 
 impl Query for Vec<WriteQuery> {
     fn build(&self) -> Result<ValidQuery, Error> {
-        let mut qlines = Vec::new();
-
-        for q in self {
-            let valid_query = q.build()?;
-            qlines.push(valid_query.0);
-        }
-
-        Ok(ValidQuery(qlines.join("\n")))
+        self.build_with_opts(false)
     }
 
     fn build_with_opts(&self, use_v2: bool) -> Result<ValidQuery, Error> {
-        let mut qlines = Vec::new();
-
-        for q in self {
-            let valid_query = q.build_with_opts(use_v2)?;
-            qlines.push(valid_query.0);
+        let mut buf = String::new();
+        for (i, q) in self.iter().enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+            Point::from(q).write_to(&mut buf, use_v2)?;
         }
-
-        Ok(ValidQuery(qlines.join("\n")))
+        Ok(ValidQuery(buf))
     }
 
     fn get_type(&self) -> QueryType {
@@ -295,7 +581,9 @@ impl Query for Vec<WriteQuery> {
 
 #[cfg(test)]
 mod tests {
+    use super::{DuplicatePolicy, WriteQuery};
     use crate::query::{InfluxDbWriteable, Query, Timestamp};
+    use crate::Error;
 
     #[test]
     fn test_write_builder_empty_query() {
@@ -463,4 +751,228 @@ mod tests {
 weather,location=us-midwest temperature=65i 12"#
         );
     }
+
+    #[test]
+    fn test_sort_tags() {
+        let query = Timestamp::Hours(11)
+            .try_into_query("weather")
+            .unwrap()
+            .add_field("temperature", 82)
+            .add_tag("season", "summer")
+            .add_tag("location", "us-midwest")
+            .sort_tags()
+            .build();
+
+        assert_eq!(
+            query.unwrap(),
+            "weather,location=us-midwest,season=summer temperature=82i 11"
+        );
+    }
+
+    #[test]
+    fn test_series_key_ignores_tag_order() {
+        let q0 = Timestamp::Hours(11)
+            .try_into_query("weather")
+            .unwrap()
+            .add_tag("season", "summer")
+            .add_tag("location", "us-midwest");
+        let q1 = Timestamp::Hours(12)
+            .try_into_query("weather")
+            .unwrap()
+            .add_tag("location", "us-midwest")
+            .add_tag("season", "summer");
+        let q2 = Timestamp::Hours(12)
+            .try_into_query("weather")
+            .unwrap()
+            .add_tag("location", "us-east");
+
+        assert_eq!(q0.series_key(), q1.series_key());
+        assert_ne!(q0.series_key(), q2.series_key());
+        assert_eq!(q0.series_key().measurement(), "weather");
+        assert_eq!(
+            q0.series_key().tags(),
+            [
+                ("location".to_owned(), "us-midwest".to_owned()),
+                ("season".to_owned(), "summer".to_owned())
+            ]
+        );
+    }
+
+    fn duplicate_batch() -> Vec<WriteQuery> {
+        vec![
+            Timestamp::Seconds(1)
+                .try_into_query("weather")
+                .unwrap()
+                .add_tag("season", "summer")
+                .add_tag("location", "us-midwest")
+                .add_field("temperature", 82),
+            Timestamp::Seconds(2)
+                .try_into_query("weather")
+                .unwrap()
+                .add_tag("location", "us-midwest")
+                .add_field("temperature", 83),
+            Timestamp::Milliseconds(1000)
+                .try_into_query("weather")
+                .unwrap()
+                .add_tag("location", "us-midwest")
+                .add_tag("season", "summer")
+                .add_field("temperature", 84)
+                .add_field("humidity", 30),
+        ]
+    }
+
+    #[test]
+    fn test_canonicalize_keep_last() {
+        let batch = WriteQuery::canonicalize(duplicate_batch(), DuplicatePolicy::KeepLast);
+
+        assert_eq!(
+            batch.unwrap().build().unwrap().get(),
+            r#"weather,location=us-midwest,season=summer temperature=84i,humidity=30i 1
+weather,location=us-midwest temperature=83i 2"#
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_keep_first() {
+        let batch = WriteQuery::canonicalize(duplicate_batch(), DuplicatePolicy::KeepFirst);
+
+        assert_eq!(
+            batch.unwrap().build().unwrap().get(),
+            r#"weather,location=us-midwest,season=summer temperature=82i,humidity=30i 1
+weather,location=us-midwest temperature=83i 2"#
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_error() {
+        let batch = WriteQuery::canonicalize(duplicate_batch(), DuplicatePolicy::Error);
+
+        assert!(matches!(batch, Err(Error::InvalidQueryError { .. })));
+    }
+
+    #[test]
+    fn test_canonicalize_overflowing_timestamps() {
+        let point = |ts: Timestamp, value: i32| {
+            ts.try_into_query("weather")
+                .unwrap()
+                .add_field("temperature", value)
+        };
+        let batch = WriteQuery::canonicalize(
+            [
+                point(Timestamp::Hours(u128::MAX), 1),
+                point(Timestamp::Hours(u128::MAX), 2),
+                point(Timestamp::Minutes(u128::MAX), 3),
+            ],
+            DuplicatePolicy::KeepLast,
+        )
+        .unwrap();
+
+        assert_eq!(batch.len(), 2);
+    }
+
+    #[test]
+    fn test_write_builder_extended_types() {
+        use std::borrow::Cow;
+        use std::num::NonZeroU32;
+        use std::sync::Arc;
+
+        let query = Timestamp::Hours(11)
+            .try_into_query("weather")
+            .unwrap()
+            .add_field("size", 3usize)
+            .add_field("offset", -3isize)
+            .add_field("count", NonZeroU32::new(7).unwrap())
+            .add_field("grade", 'A')
+            .add_field("cow", Cow::Borrowed("moo"))
+            .add_field("arc", Arc::<str>::from("shared"))
+            .add_field("boxed", Box::<str>::from("boxed"))
+            .build_with_opts(true);
+
+        assert_eq!(
+            query.unwrap(),
+            r#"weather size=3u,offset=-3i,count=7u,grade="A",cow="moo",arc="shared",boxed="boxed" 11"#
+        );
+    }
+
+    #[test]
+    fn test_checked_conversions() {
+        use super::Type;
+        use std::time::Duration;
+
+        assert!(matches!(
+            Type::try_from(-5i128),
+            Ok(Type::SignedInteger(-5))
+        ));
+        assert!(Type::try_from(i128::MAX).is_err());
+        assert!(matches!(
+            Type::try_from(5u128),
+            Ok(Type::UnsignedInteger(5))
+        ));
+        assert!(Type::try_from(u128::MAX).is_err());
+        assert!(matches!(
+            Type::try_from(Duration::from_millis(1500)),
+            Ok(Type::SignedInteger(1_500_000_000))
+        ));
+        assert!(Type::try_from(Duration::MAX).is_err());
+
+        let query = Timestamp::Seconds(1)
+            .try_into_query("job")
+            .unwrap()
+            .add_field("elapsed", Duration::from_millis(1500))
+            .add_field("timeout", Some(Duration::MAX))
+            .build();
+        assert_eq!(
+            query.unwrap(),
+            "job elapsed=1500000000i,timeout=9223372036854775807i 1"
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff_field_values() {
+        use super::Type;
+
+        let ts = jiff::Timestamp::from_millisecond(1500).unwrap();
+        assert!(matches!(
+            Type::try_from(ts),
+            Ok(Type::SignedInteger(1_500_000_000))
+        ));
+        assert!(matches!(
+            Type::try_from(ts.to_zoned(jiff::tz::TimeZone::UTC)),
+            Ok(Type::SignedInteger(1_500_000_000))
+        ));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_rust_decimal() {
+        use super::{DecimalPolicy, Type};
+        use rust_decimal::Decimal;
+
+        let value = Decimal::new(12345, 2);
+        assert!(matches!(Type::from(value), Type::Float(f) if f == 123.45));
+        assert!(matches!(
+            Type::from_rust_decimal(value, DecimalPolicy::Text),
+            Type::Text(t) if t == "123.45"
+        ));
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn test_bigdecimal() {
+        use super::{DecimalPolicy, Type};
+        use bigdecimal::BigDecimal;
+        use std::str::FromStr;
+
+        let value = BigDecimal::from_str("123.450000000000000000001").unwrap();
+        assert!(matches!(
+            Type::from_bigdecimal(&value, DecimalPolicy::Text),
+            Ok(Type::Text(t)) if t == "123.450000000000000000001"
+        ));
+        assert!(matches!(Type::try_from(value), Ok(Type::Float(f)) if f == 123.45));
+
+        let value = BigDecimal::from_str("1e400").unwrap();
+        assert!(Type::try_from(value.clone()).is_err());
+        assert!(Type::from_bigdecimal(&value, DecimalPolicy::Text).is_ok());
+    }
 }
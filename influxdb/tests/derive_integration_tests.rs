@@ -84,6 +84,656 @@ fn test_build_nonstandard_query() {
     );
 }
 
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_renamed_keys() {
+    #[derive(InfluxDbWriteable)]
+    #[influxdb(rename_all = "kebab-case")]
+    struct RenamedWeatherReading {
+        time: DateTime<Utc>,
+        #[influxdb(tag)]
+        wind_direction: String,
+        #[influxdb(tag, rename = "host.name")]
+        host_name: String,
+        #[influxdb(rename = "Temperature")]
+        temperature: i32,
+        air_pressure: i32,
+    }
+
+    let weather_reading = RenamedWeatherReading {
+        time: Timestamp::Hours(1).try_into().unwrap(),
+        wind_direction: "north".into(),
+        host_name: "gw1".into(),
+        temperature: 20,
+        air_pressure: 100,
+    };
+    let query = weather_reading
+        .try_into_query("weather_reading")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(
+        query.get(),
+        "weather_reading,wind-direction=north,host.name=gw1 Temperature=20i,air-pressure=100i 3600000000000"
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_static_measurement() {
+    #[derive(InfluxDbWriteable)]
+    #[influxdb(measurement = "weather")]
+    struct WeatherWithMeasurement {
+        time: DateTime<Utc>,
+        pressure: i32,
+    }
+
+    assert_eq!(WeatherWithMeasurement::MEASUREMENT, "weather");
+    let weather_reading = WeatherWithMeasurement {
+        time: Timestamp::Hours(1).try_into().unwrap(),
+        pressure: 100,
+    };
+    let query = weather_reading.into_write_query().unwrap().build().unwrap();
+    assert_eq!(query.get(), "weather pressure=100i 3600000000000");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_dynamic_measurement() {
+    use influxdb::ToPoint;
+
+    #[derive(InfluxDbWriteable)]
+    struct Event {
+        time: DateTime<Utc>,
+        #[influxdb(measurement)]
+        kind: String,
+        #[influxdb(tag)]
+        source: &'static str,
+        value: i32,
+    }
+
+    let event = Event {
+        time: Timestamp::Hours(1).try_into().unwrap(),
+        kind: "login".into(),
+        source: "web",
+        value: 1,
+    };
+    let point = event.to_point("ignored").unwrap();
+    assert_eq!(
+        point.build().unwrap().get(),
+        "login,source=web value=1i 3600000000000"
+    );
+    let query = (&event).try_into_query("ignored").unwrap().build().unwrap();
+    assert_eq!(query.get(), "login,source=web value=1i 3600000000000");
+    let query = event.into_write_query().unwrap().build().unwrap();
+    assert_eq!(query.get(), "login,source=web value=1i 3600000000000");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_from_enum() {
+    use influxdb::ToPoint;
+
+    #[derive(InfluxDbWriteable)]
+    #[influxdb(rename_all = "snake_case")]
+    enum Event {
+        UserLogin {
+            time: Timestamp,
+            #[influxdb(tag)]
+            user: String,
+            attempts: u32,
+        },
+        #[influxdb(rename = "logout")]
+        UserLogout {
+            time: Timestamp,
+            #[influxdb(tag)]
+            user: String,
+            session_length: u64,
+        },
+    }
+
+    let login = Event::UserLogin {
+        time: Timestamp::Seconds(1),
+        user: "gero".into(),
+        attempts: 1,
+    };
+    let query = login.into_write_query().unwrap().build().unwrap();
+    assert_eq!(query.get(), "user_login,user=gero attempts=1i 1");
+
+    let logout = Event::UserLogout {
+        time: Timestamp::Seconds(2),
+        user: "gero".into(),
+        session_length: 1,
+    };
+    let point = logout.to_point("events").unwrap();
+    assert_eq!(
+        point.build().unwrap().get(),
+        "logout,user=gero session_length=1i 2"
+    );
+    let query = logout.into_write_query().unwrap().build().unwrap();
+    assert_eq!(query.get(), "logout,user=gero session_length=1i 2");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_from_enum_with_variant_tag() {
+    #[derive(InfluxDbWriteable)]
+    #[influxdb(
+        measurement = "events",
+        variant_tag = "kind",
+        rename_all = "kebab-case"
+    )]
+    enum Event {
+        Started { time: Timestamp, attempt: u32 },
+        StoppedEarly { time: Timestamp, code: i32 },
+    }
+
+    assert_eq!(Event::MEASUREMENT, "events");
+    let query = Event::Started {
+        time: Timestamp::Seconds(1),
+        attempt: 1,
+    }
+    .into_write_query()
+    .unwrap()
+    .build()
+    .unwrap();
+    assert_eq!(query.get(), "events,kind=started attempt=1i 1");
+    let query = Event::StoppedEarly {
+        time: Timestamp::Seconds(2),
+        code: 3,
+    }
+    .into_write_query()
+    .unwrap()
+    .build()
+    .unwrap();
+    assert_eq!(query.get(), "events,kind=stopped-early code=3i 2");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_value_types() {
+    use influxdb::{InfluxDbValue, ToPoint};
+
+    #[derive(InfluxDbValue)]
+    #[influxdb(rename_all = "lowercase")]
+    enum Direction {
+        North,
+        #[influxdb(rename = "S")]
+        South,
+    }
+
+    #[derive(InfluxDbValue)]
+    struct SensorId(u32);
+
+    #[derive(InfluxDbWriteable)]
+    struct Reading {
+        time: Timestamp,
+        #[influxdb(tag)]
+        direction: Direction,
+        #[influxdb(tag)]
+        sensor: SensorId,
+        speed: f64,
+    }
+
+    #[derive(InfluxDbWriteable)]
+    struct Wrapped(Reading);
+
+    let reading = Reading {
+        time: Timestamp::Seconds(1),
+        direction: Direction::North,
+        sensor: SensorId(7),
+        speed: 1.5,
+    };
+    let point = reading.to_point("wind").unwrap();
+    assert_eq!(
+        point.build().unwrap().get(),
+        "wind,direction=north,sensor=7 speed=1.5 1"
+    );
+
+    let wrapped = Wrapped(Reading {
+        direction: Direction::South,
+        ..reading
+    });
+    let point = wrapped.to_point("wind").unwrap();
+    assert_eq!(
+        point.build().unwrap().get(),
+        "wind,direction=S,sensor=7 speed=1.5 1"
+    );
+    let query = wrapped.try_into_query("wind").unwrap().build().unwrap();
+    assert_eq!(query.get(), "wind,direction=S,sensor=7 speed=1.5 1");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_owned_value_types() {
+    use influxdb::Type;
+
+    enum Kind {
+        Cold,
+        Warm,
+    }
+
+    impl From<Kind> for Type {
+        fn from(kind: Kind) -> Self {
+            match kind {
+                Kind::Cold => Type::Text("cold".into()),
+                Kind::Warm => Type::Text("warm".into()),
+            }
+        }
+    }
+
+    // `&Kind` can't be borrowed by a `Point`, so only `InfluxDbWriteable` is implemented
+    #[derive(InfluxDbWriteable)]
+    struct Reading {
+        time: Timestamp,
+        #[influxdb(tag)]
+        kind: Kind,
+        temperature: f64,
+    }
+
+    #[derive(InfluxDbWriteable)]
+    struct WrappedReading(Reading);
+
+    #[derive(InfluxDbWriteable)]
+    struct Generic<T: Into<Type>> {
+        time: Timestamp,
+        value: T,
+    }
+
+    let query = Reading {
+        time: Timestamp::Seconds(1),
+        kind: Kind::Cold,
+        temperature: 1.5,
+    }
+    .try_into_query("weather")
+    .unwrap();
+    assert_eq!(
+        query.build().unwrap().get(),
+        "weather,kind=cold temperature=1.5 1"
+    );
+
+    let query = WrappedReading(Reading {
+        time: Timestamp::Seconds(2),
+        kind: Kind::Warm,
+        temperature: 2.5,
+    })
+    .try_into_query("weather")
+    .unwrap();
+    assert_eq!(
+        query.build().unwrap().get(),
+        "weather,kind=warm temperature=2.5 2"
+    );
+
+    let query = Generic {
+        time: Timestamp::Seconds(1),
+        value: Kind::Warm,
+    }
+    .try_into_query("weather")
+    .unwrap();
+    assert_eq!(query.build().unwrap().get(), "weather value=\"warm\" 1");
+
+    let generic = Generic {
+        time: Timestamp::Seconds(2),
+        value: 1.5,
+    };
+    let point = influxdb::ToPoint::to_point(&generic, "weather").unwrap();
+    assert_eq!(point.build().unwrap().get(), "weather value=1.5 2");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_flattened_fields() {
+    use influxdb::{InfluxDbFields, ToPoint};
+
+    #[derive(InfluxDbFields)]
+    struct Host {
+        #[influxdb(tag)]
+        region: String,
+        #[influxdb(tag)]
+        host: String,
+    }
+
+    #[derive(InfluxDbFields)]
+    struct Deployment {
+        #[influxdb(flatten)]
+        host: Host,
+        #[influxdb(tag)]
+        version: &'static str,
+        uptime: u64,
+    }
+
+    #[derive(InfluxDbWriteable)]
+    struct Request {
+        time: Timestamp,
+        #[influxdb(flatten, prefix = "env_")]
+        deployment: Deployment,
+        latency: f64,
+    }
+
+    let request = Request {
+        time: Timestamp::Seconds(1),
+        deployment: Deployment {
+            host: Host {
+                region: "eu".into(),
+                host: "a".into(),
+            },
+            version: "1.0",
+            uptime: 10,
+        },
+        latency: 0.5,
+    };
+    let expected = "requests,env_region=eu,env_host=a,env_version=1.0 env_uptime=10i,latency=0.5 1";
+    let point = request.to_point("requests").unwrap();
+    assert_eq!(point.build().unwrap().get(), expected);
+    let query = request.try_into_query("requests").unwrap().build().unwrap();
+    assert_eq!(query.get(), expected);
+}
+
+#[cfg(all(feature = "derive", feature = "jiff"))]
+#[test]
+fn test_build_query_with_jiff_time() {
+    #[derive(InfluxDbWriteable)]
+    struct JiffReading {
+        time: jiff::Timestamp,
+        pressure: i32,
+    }
+
+    let reading = JiffReading {
+        time: jiff::Timestamp::from_second(3600).unwrap(),
+        pressure: 100,
+    };
+    let query = reading.try_into_query("weather_reading").unwrap();
+    assert_eq!(
+        query.build().unwrap().get(),
+        "weather_reading pressure=100i 3600000000000"
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_struct_tags() {
+    use influxdb::ToPoint;
+
+    fn build_tags() -> Vec<(&'static str, String)> {
+        vec![("version", format!("{}.{}", 1, 2))]
+    }
+
+    #[derive(InfluxDbWriteable)]
+    #[influxdb(tag(name = "source", value = "sensor-gw"))]
+    #[influxdb(default_tags = build_tags)]
+    struct Reading {
+        time: Timestamp,
+        #[influxdb(tag)]
+        location: String,
+        value: f64,
+    }
+
+    let reading = Reading {
+        time: Timestamp::Seconds(1),
+        location: "berlin".into(),
+        value: 1.5,
+    };
+    let expected = "readings,source=sensor-gw,version=1.2,location=berlin value=1.5 1";
+    let point = reading.to_point("readings").unwrap();
+    assert_eq!(point.build().unwrap().get(), expected);
+    let query = reading.try_into_query("readings").unwrap().build().unwrap();
+    assert_eq!(query.get(), expected);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_conversion_functions() {
+    use influxdb::{ToPoint, Type};
+    use std::net::IpAddr;
+    use std::time::Duration;
+
+    fn ip_to_type(ip: &IpAddr) -> Result<Type, std::convert::Infallible> {
+        Ok(Type::Text(ip.to_string()))
+    }
+
+    mod millis {
+        use influxdb::Type;
+        use std::time::Duration;
+
+        pub fn serialize(duration: &Duration) -> Result<Type, &'static str> {
+            i64::try_from(duration.as_millis())
+                .map(Type::SignedInteger)
+                .map_err(|_| "duration too long")
+        }
+    }
+
+    #[derive(InfluxDbWriteable)]
+    struct Request {
+        time: Timestamp,
+        #[influxdb(tag, serialize_with = "ip_to_type")]
+        client: IpAddr,
+        #[influxdb(with = "millis")]
+        latency: Duration,
+    }
+
+    let request = Request {
+        time: Timestamp::Seconds(1),
+        client: IpAddr::from([127, 0, 0, 1]),
+        latency: Duration::from_millis(15),
+    };
+    let expected = "requests,client=127.0.0.1 latency=15i 1";
+    let point = request.to_point("requests").unwrap();
+    assert_eq!(point.build().unwrap().get(), expected);
+    let query = request.try_into_query("requests").unwrap().build().unwrap();
+    assert_eq!(query.get(), expected);
+
+    let request = Request {
+        time: Timestamp::Seconds(1),
+        client: IpAddr::from([127, 0, 0, 1]),
+        latency: Duration::MAX,
+    };
+    let err = request.try_into_query("requests").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to convert field value: duration too long"
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_with_duration_fields() {
+    use influxdb::ToPoint;
+    use std::time::Duration;
+
+    #[derive(InfluxDbWriteable)]
+    struct Job {
+        time: Timestamp,
+        elapsed: Duration,
+        timeout: Option<Duration>,
+    }
+
+    let job = Job {
+        time: Timestamp::Seconds(1),
+        elapsed: Duration::from_millis(15),
+        timeout: None,
+    };
+    let point = job.to_point("job").unwrap();
+    assert_eq!(point.build().unwrap().get(), "job elapsed=15000000i 1");
+    let query = job.try_into_query("job").unwrap().build().unwrap();
+    assert_eq!(query.get(), "job elapsed=15000000i 1");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_select_query() {
+    use influxdb::InfluxDbFields;
+
+    #[derive(InfluxDbFields)]
+    struct Host {
+        #[influxdb(tag)]
+        region: String,
+    }
+
+    #[derive(InfluxDbWriteable)]
+    #[influxdb(measurement = "weather", select)]
+    struct Weather {
+        time: Timestamp,
+        #[influxdb(tag)]
+        wind_direction: String,
+        #[influxdb(flatten, prefix = "host_")]
+        host: Host,
+        #[influxdb(rename = "temp")]
+        temperature: i32,
+        #[influxdb(ignore)]
+        _note: String,
+    }
+
+    let query = Weather::select()
+        .where_wind_direction("north")
+        .where_temperature(21)
+        .between(Timestamp::Seconds(1), Timestamp::Seconds(2))
+        .build()
+        .unwrap();
+    assert_eq!(
+        query.get(),
+        "SELECT \"wind_direction\", \"host_region\", \"temp\" FROM \"weather\" \
+         WHERE \"wind_direction\" = 'north' AND \"temp\" = 21 \
+         AND time >= 1000000000 AND time < 2000000000"
+    );
+
+    let query =
+        ReadQuery::try_from(Weather::select_from("weather_2").descending().limit(1)).unwrap();
+    assert_eq!(
+        query.build().unwrap().get(),
+        "SELECT \"wind_direction\", \"host_region\", \"temp\" FROM \"weather_2\" \
+         ORDER BY time DESC LIMIT 1"
+    );
+
+    let query = Weather::select().with_epoch(influxdb::Precision::Seconds);
+    assert_eq!(query.get_epoch(), Some(influxdb::Precision::Seconds));
+    assert_eq!(
+        ReadQuery::try_from(query).unwrap().get_epoch(),
+        Some(influxdb::Precision::Seconds)
+    );
+}
+
+#[cfg(all(feature = "derive", feature = "serde"))]
+#[test]
+fn test_read_rows_into_struct() {
+    use influxdb::integrations::serde_integration::DatabaseQueryResult;
+    use influxdb::InfluxDbReadable;
+
+    #[derive(Debug, PartialEq, InfluxDbReadable)]
+    struct Host {
+        #[influxdb(tag)]
+        region: String,
+    }
+
+    #[derive(Debug, PartialEq, InfluxDbReadable)]
+    #[influxdb(rename_all = "camelCase")]
+    struct Reading {
+        time: DateTime<Utc>,
+        #[influxdb(measurement)]
+        name: String,
+        #[influxdb(tag)]
+        location: String,
+        #[influxdb(flatten, prefix = "host_")]
+        host: Host,
+        wind_speed: Option<f64>,
+        #[influxdb(rename = "temp")]
+        temperature: i32,
+        #[influxdb(ignore)]
+        note: String,
+    }
+
+    let mut result: DatabaseQueryResult = serde_json::from_value(serde_json::json!({
+        "results": [{
+            "statement_id": 0,
+            "series": [{
+                "name": "weather",
+                "tags": { "location": "berlin" },
+                "columns": ["time", "host_region", "temp", "windSpeed"],
+                "values": [
+                    ["1970-01-01T00:00:01Z", "eu", 21, 3.5],
+                    ["1970-01-01T00:00:02Z", "eu", 22, null]
+                ]
+            }]
+        }]
+    }))
+    .unwrap();
+    let rows = result.read_next::<Reading>().unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            Reading {
+                time: Timestamp::Seconds(1).try_into().unwrap(),
+                name: "weather".into(),
+                location: "berlin".into(),
+                host: Host {
+                    region: "eu".into()
+                },
+                wind_speed: Some(3.5),
+                temperature: 21,
+                note: String::new(),
+            },
+            Reading {
+                time: Timestamp::Seconds(2).try_into().unwrap(),
+                name: "weather".into(),
+                location: "berlin".into(),
+                host: Host {
+                    region: "eu".into()
+                },
+                wind_speed: None,
+                temperature: 22,
+                note: String::new(),
+            },
+        ]
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_point_by_reference() {
+    use influxdb::ToPoint;
+
+    let weather_reading = WeatherReading {
+        time: Timestamp::Hours(1).try_into().unwrap(),
+        humidity: 30,
+        pressure: 100,
+        wind_strength: Some(5),
+    };
+    let point = weather_reading.to_point("weather_reading").unwrap();
+    assert_eq!(
+        point.build().unwrap().get(),
+        "weather_reading,wind_strength=5 pressure=100i 3600000000000"
+    );
+    assert_eq!(
+        point.build().unwrap().get(),
+        weather_reading
+            .try_into_query("weather_reading")
+            .unwrap()
+            .build()
+            .unwrap()
+            .get()
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_build_query_by_reference() {
+    let weather_reading = WeatherReading {
+        time: Timestamp::Hours(1).try_into().unwrap(),
+        humidity: 30,
+        pressure: 100,
+        wind_strength: Some(5),
+    };
+    let query = (&weather_reading)
+        .try_into_query("weather_reading")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(
+        query.get(),
+        "weather_reading,wind_strength=5 pressure=100i 3600000000000"
+    );
+    assert_eq!(weather_reading.pressure, 100);
+}
+
 #[cfg(feature = "derive")]
 /// INTEGRATION TEST
 ///
@@ -651,17 +651,38 @@ async fn test_serde_multi_query() {
 
 /// INTEGRATION TEST
 ///
-/// This integration test tests whether using the wrong query method fails building the query
+/// This integration test tests that statements other than SELECT and SHOW can be sent as JSON
+/// queries, and that errors of single statements are reported
 #[tokio::test]
 #[cfg(feature = "serde")]
 #[cfg(not(tarpaulin_include))]
-async fn test_wrong_query_errors() {
-    let client = create_client("test_name");
-    let result = client
-        .json_query(ReadQuery::new("CREATE DATABASE this_should_fail"))
-        .await;
-    assert!(
-        result.is_err(),
-        "Should only build SELECT and SHOW queries."
-    );
+async fn test_json_query_any_statement() {
+    const TEST_NAME: &str = "test_json_query_any_statement";
+
+    run_test(
+        || async move {
+            let client = create_client(TEST_NAME);
+            let mut result = client
+                .json_query(ReadQuery::new(format!("CREATE DATABASE {TEST_NAME}")))
+                .await
+                .expect("could not create db");
+            assert_result_ok(&result.next_statement().and_then(|result| result.check()));
+
+            let query = "SHOW MEASUREMENTS ON this_database_does_not_exist";
+            let raw_result = client.query(ReadQuery::new(query)).await;
+            assert_result_err(&raw_result);
+
+            let mut result = client
+                .json_query(ReadQuery::new(query))
+                .await
+                .expect("statement errors are reported by the statement results");
+            let statement = result.next_statement().unwrap();
+            assert_result_err(&statement.check());
+            assert_result_err(&statement.table());
+        },
+        || async move {
+            delete_db(TEST_NAME).await.expect("could not clean up db");
+        },
+    )
+    .await;
 }
@@ -0,0 +1,24 @@
+use influxdb::{InfluxDbWriteable, Timestamp};
+
+#[derive(InfluxDbWriteable)]
+struct Weather {
+    time: Timestamp,
+    #[influxdb(tag, ignore)]
+    location: String,
+    temperature: f64,
+}
+
+#[derive(InfluxDbWriteable)]
+struct Conversion {
+    #[influxdb(time, serialize_with = "to_type")]
+    recorded_at: Timestamp,
+}
+
+#[derive(InfluxDbWriteable)]
+struct MultipleConversions {
+    time: Timestamp,
+    #[influxdb(with = "module", serialize_with = "to_type")]
+    temperature: f64,
+}
+
+fn main() {}
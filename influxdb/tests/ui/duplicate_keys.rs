@@ -0,0 +1,29 @@
+use influxdb::{InfluxDbWriteable, Timestamp};
+
+#[derive(InfluxDbWriteable)]
+struct Weather {
+    time: Timestamp,
+    #[influxdb(tag)]
+    location: String,
+    #[influxdb(rename = "location")]
+    place: String,
+}
+
+#[derive(InfluxDbWriteable)]
+#[influxdb(rename_all = "lowercase")]
+struct Renamed {
+    time: Timestamp,
+    wind_speed: f64,
+    #[influxdb(rename = "wind_speed")]
+    windspeed: f64,
+}
+
+#[derive(InfluxDbWriteable)]
+#[influxdb(tag(name = "location", value = "berlin"))]
+struct StaticTag {
+    time: Timestamp,
+    #[influxdb(tag)]
+    location: String,
+}
+
+fn main() {}
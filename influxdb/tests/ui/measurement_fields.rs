@@ -0,0 +1,22 @@
+use influxdb::{InfluxDbWriteable, Timestamp};
+
+#[derive(InfluxDbWriteable)]
+struct MultipleMeasurements {
+    time: Timestamp,
+    #[influxdb(measurement)]
+    name: String,
+    #[influxdb(measurement)]
+    other_name: String,
+    value: f64,
+}
+
+#[derive(InfluxDbWriteable)]
+#[influxdb(measurement = "weather")]
+struct StaticAndField {
+    time: Timestamp,
+    #[influxdb(measurement)]
+    name: String,
+    value: f64,
+}
+
+fn main() {}
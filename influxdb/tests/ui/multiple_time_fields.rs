@@ -0,0 +1,11 @@
+use influxdb::{InfluxDbWriteable, Timestamp};
+
+#[derive(InfluxDbWriteable)]
+struct Weather {
+    time: Timestamp,
+    #[influxdb(time)]
+    recorded_at: Timestamp,
+    temperature: f64,
+}
+
+fn main() {}
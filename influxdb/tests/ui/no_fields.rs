@@ -0,0 +1,9 @@
+use influxdb::InfluxDbWriteable;
+
+#[derive(InfluxDbWriteable)]
+struct Unit;
+
+#[derive(InfluxDbWriteable)]
+struct Empty {}
+
+fn main() {}
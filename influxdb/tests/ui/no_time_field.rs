@@ -0,0 +1,8 @@
+use influxdb::InfluxDbWriteable;
+
+#[derive(InfluxDbWriteable)]
+struct Weather {
+    temperature: f64,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use influxdb::{InfluxDbWriteable, Timestamp};
+
+#[derive(InfluxDbWriteable)]
+#[influxdb(measurement = "weather")]
+struct WithoutSelect {
+    time: Timestamp,
+    temperature: f64,
+}
+
+#[derive(InfluxDbWriteable)]
+#[influxdb(select)]
+struct Generic<T: Into<influxdb::Type>> {
+    time: Timestamp,
+    value: T,
+}
+
+#[derive(InfluxDbWriteable)]
+#[influxdb(select, variant_tag = "kind")]
+enum Event {
+    Started { time: Timestamp, attempt: u32 },
+}
+
+fn main() {
+    let _ = WithoutSelect::select();
+}
@@ -0,0 +1,11 @@
+use influxdb::{InfluxDbWriteable, Timestamp};
+
+#[derive(InfluxDbWriteable)]
+struct Weather(Timestamp, f64);
+
+#[derive(InfluxDbWriteable)]
+enum Event {
+    Login(Timestamp),
+}
+
+fn main() {}
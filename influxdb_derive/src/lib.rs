@@ -1,8 +1,13 @@
 use proc_macro::TokenStream;
 
+mod readable;
+mod rename;
+mod value;
 mod writeable;
+use readable::expand_readable;
 use syn::parse_macro_input;
-use writeable::expand_writeable;
+use value::expand_value;
+use writeable::{expand_fields_derive, expand_writeable};
 
 #[proc_macro_derive(InfluxDbWriteable, attributes(influxdb))]
 pub fn derive_writeable(input: TokenStream) -> TokenStream {
@@ -10,3 +15,24 @@ pub fn derive_writeable(input: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+#[proc_macro_derive(InfluxDbValue, attributes(influxdb))]
+pub fn derive_value(input: TokenStream) -> TokenStream {
+    expand_value(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(InfluxDbFields, attributes(influxdb))]
+pub fn derive_fields(input: TokenStream) -> TokenStream {
+    expand_fields_derive(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(InfluxDbReadable, attributes(influxdb))]
+pub fn derive_readable(input: TokenStream) -> TokenStream {
+    expand_readable(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,65 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::writeable::{parse_fields, WriteableStruct};
+
+pub fn expand_readable(input: DeriveInput) -> syn::Result<TokenStream> {
+    let strukt = WriteableStruct::from_attrs(&input.attrs)?;
+    if let Some(variant_tag) = &strukt.variant_tag {
+        return Err(syn::Error::new_spanned(
+            variant_tag,
+            "variant_tag can only be used on enums",
+        ));
+    }
+
+    let ident = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            // Newtypes read their only field
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                quote!(Ok(Self(::influxdb::InfluxDbReadable::from_row(row)?)))
+            }
+            fields => {
+                let fields = parse_fields(ident, fields.clone())?;
+                let assignments = fields.iter().map(|wf| {
+                    let ident = &wf.ident;
+                    let value = if wf.is_ignore {
+                        quote!(::core::default::Default::default())
+                    } else if wf.is_time {
+                        quote!(row.time()?)
+                    } else if wf.is_measurement {
+                        quote!(row.measurement()?)
+                    } else if let Some(prefix) = &wf.flatten {
+                        quote!(::influxdb::InfluxDbReadable::from_row(&row.with_prefix(#prefix))?)
+                    } else if wf.is_tag {
+                        let key = wf.key(strukt.rename_all);
+                        quote!(row.tag(#key)?)
+                    } else {
+                        let key = wf.key(strukt.rename_all);
+                        quote!(row.field(#key)?)
+                    };
+                    quote!(#ident: #value)
+                });
+                quote!(Ok(Self { #(#assignments,)* }))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "#[derive(InfluxDbReadable)] can only be used on structs",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::influxdb::InfluxDbReadable for #ident #ty_generics #where_clause {
+            fn from_row(
+                row: &::influxdb::ReadRow<'_>
+            ) -> ::core::result::Result<Self, ::influxdb::Error> {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,182 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, Data, DataEnum, DeriveInput, Fields, GenericParam, Generics, LitStr, Meta, Token,
+    Type, WhereClause,
+};
+
+use crate::rename::RenameRule;
+use crate::writeable::{kw, variant_name};
+
+enum ValueAttr {
+    RenameAll(LitStr),
+}
+
+impl Parse for ValueAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::rename_all) {
+            input.parse::<kw::rename_all>()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::RenameAll(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+fn rename_all(attrs: &[Attribute]) -> syn::Result<RenameRule> {
+    let mut rename_all = RenameRule::default();
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(list) if list.path.is_ident("influxdb") => {
+                let attrs =
+                    list.parse_args_with(Punctuated::<ValueAttr, Token![,]>::parse_terminated)?;
+                for attr in attrs {
+                    match attr {
+                        ValueAttr::RenameAll(lit) => rename_all = RenameRule::from_lit(&lit)?,
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(rename_all)
+}
+
+/// Adds the lifetime of the generated `PointValue` impls to the generics of the input
+pub(crate) fn with_point_lifetime(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    generics
+        .params
+        .insert(0, GenericParam::Lifetime(syn::parse_quote!('__influxdb)));
+    generics
+}
+
+fn where_clause(generics: &Generics) -> WhereClause {
+    generics.where_clause.clone().unwrap_or(WhereClause {
+        where_token: Default::default(),
+        predicates: Punctuated::new(),
+    })
+}
+
+/// Unit-only enums are written as the (renamed) name of their variant
+fn expand_enum(input: &DeriveInput, inum: &DataEnum) -> syn::Result<TokenStream> {
+    let rename_all = rename_all(&input.attrs)?;
+    let mut arms = Vec::new();
+    for variant in &inum.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "#[derive(InfluxDbValue)] only supports enums with unit variants",
+            ));
+        }
+        let variant_ident = &variant.ident;
+        let name = variant_name(&variant.attrs, variant_ident, rename_all)?;
+        arms.push(quote!(Self::#variant_ident => #name));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let point_generics = with_point_lifetime(&input.generics);
+    let (point_impl_generics, _, _) = point_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Returns the value written to InfluxDB for this variant
+            pub fn as_influxdb_str(&self) -> &'static str {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for ::influxdb::Type #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::influxdb::Type::Text(value.as_influxdb_str().to_owned())
+            }
+        }
+
+        impl #point_impl_generics ::core::convert::From<#ident #ty_generics>
+            for ::influxdb::PointValue<'__influxdb> #where_clause
+        {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::influxdb::PointValue::Text(::std::borrow::Cow::Borrowed(value.as_influxdb_str()))
+            }
+        }
+
+        impl #point_impl_generics ::core::convert::From<&'__influxdb #ident #ty_generics>
+            for ::influxdb::PointValue<'__influxdb> #where_clause
+        {
+            fn from(value: &'__influxdb #ident #ty_generics) -> Self {
+                ::influxdb::PointValue::Text(::std::borrow::Cow::Borrowed(value.as_influxdb_str()))
+            }
+        }
+    })
+}
+
+/// Newtypes are written as their only field
+fn expand_newtype(input: &DeriveInput, inner_ty: &Type) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let point_generics = with_point_lifetime(&input.generics);
+    let (point_impl_generics, _, _) = point_generics.split_for_impl();
+
+    let mut type_where_clause = where_clause(&input.generics);
+    type_where_clause
+        .predicates
+        .push(syn::parse_quote!(#inner_ty: ::core::convert::Into<::influxdb::Type>));
+    let mut owned_where_clause = where_clause(&input.generics);
+    owned_where_clause.predicates.push(syn::parse_quote!(
+        #inner_ty: ::core::convert::Into<::influxdb::PointValue<'__influxdb>>
+    ));
+    let mut borrowed_where_clause = where_clause(&input.generics);
+    borrowed_where_clause.predicates.push(syn::parse_quote!(
+        &'__influxdb #inner_ty: ::core::convert::Into<::influxdb::PointValue<'__influxdb>>
+    ));
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for ::influxdb::Type #type_where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::core::convert::Into::into(value.0)
+            }
+        }
+
+        impl #point_impl_generics ::core::convert::From<#ident #ty_generics>
+            for ::influxdb::PointValue<'__influxdb> #owned_where_clause
+        {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::core::convert::Into::into(value.0)
+            }
+        }
+
+        impl #point_impl_generics ::core::convert::From<&'__influxdb #ident #ty_generics>
+            for ::influxdb::PointValue<'__influxdb> #borrowed_where_clause
+        {
+            fn from(value: &'__influxdb #ident #ty_generics) -> Self {
+                ::core::convert::Into::into(&value.0)
+            }
+        }
+    })
+}
+
+pub fn expand_value(input: DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        Data::Enum(inum) => expand_enum(&input, inum),
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                expand_newtype(&input, &fields.unnamed[0].ty)
+            }
+            fields => Err(syn::Error::new_spanned(
+                fields,
+                "#[derive(InfluxDbValue)] only supports structs with exactly one unnamed field",
+            )),
+        },
+        Data::Union(cdu) => Err(syn::Error::new(
+            cdu.union_token.span,
+            "#[derive(InfluxDbValue)] can only be used on enums and newtype structs",
+        )),
+    }
+}
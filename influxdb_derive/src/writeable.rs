@@ -1,29 +1,64 @@
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
+use syn::ext::IdentExt as _;
+use syn::parenthesized;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{
-    AngleBracketedGenericArguments, Data, DeriveInput, Field, Fields, GenericArgument, Ident,
-    Lifetime, Meta, PathArguments, PredicateType, Token, Type, TypeParamBound, WhereClause,
-    WherePredicate,
+    AngleBracketedGenericArguments, Attribute, Data, DataEnum, DeriveInput, Field, Fields,
+    GenericArgument, Generics, Ident, Lifetime, LitStr, Meta, Path, PathArguments, PredicateType,
+    Token, Type, TypeParamBound, WhereClause, WherePredicate,
 };
+
+use std::collections::HashSet;
+
+use crate::rename::RenameRule;
+use crate::value::with_point_lifetime;
 use syn_path::type_path;
 
 #[derive(Debug)]
-struct WriteableField {
-    ident: Ident,
+pub(crate) struct WriteableField {
+    pub(crate) ident: Ident,
     ty: Type,
-    is_time: bool,
-    is_tag: bool,
-    is_ignore: bool,
+    rename: Option<LitStr>,
+    pub(crate) is_time: bool,
+    pub(crate) is_tag: bool,
+    pub(crate) is_ignore: bool,
+    pub(crate) is_measurement: bool,
+    /// The key prefix of a `#[influxdb(flatten)]` field
+    pub(crate) flatten: Option<LitStr>,
+    /// The function converting the field into a `Type`
+    serialize_with: Option<Path>,
+}
+
+impl WriteableField {
+    /// The key of this field in the line protocol
+    pub(crate) fn key(&self, rename_all: RenameRule) -> String {
+        match &self.rename {
+            Some(rename) => rename.value(),
+            None => rename_all.apply_to_field(&self.ident.unraw().to_string()),
+        }
+    }
 }
 
-mod kw {
+pub(crate) mod kw {
     use syn::custom_keyword;
 
     custom_keyword!(time);
     custom_keyword!(tag);
     custom_keyword!(ignore);
+    custom_keyword!(measurement);
+    custom_keyword!(rename);
+    custom_keyword!(rename_all);
+    custom_keyword!(variant_tag);
+    custom_keyword!(flatten);
+    custom_keyword!(prefix);
+    custom_keyword!(name);
+    custom_keyword!(value);
+    custom_keyword!(default_tags);
+    custom_keyword!(with);
+    custom_keyword!(serialize_with);
+    custom_keyword!(select);
 }
 
 #[allow(dead_code)] // TODO do we need to store the keywords?
@@ -31,6 +66,12 @@ enum FieldAttr {
     Time(kw::time),
     Tag(kw::tag),
     Ignore(kw::ignore),
+    Measurement(kw::measurement),
+    Rename(kw::rename, LitStr),
+    Flatten(kw::flatten),
+    Prefix(kw::prefix, LitStr),
+    With(kw::with, LitStr),
+    SerializeWith(kw::serialize_with, LitStr),
 }
 
 impl Parse for FieldAttr {
@@ -42,6 +83,26 @@ impl Parse for FieldAttr {
             Ok(Self::Tag(input.parse()?))
         } else if lookahead.peek(kw::ignore) {
             Ok(Self::Ignore(input.parse()?))
+        } else if lookahead.peek(kw::measurement) {
+            Ok(Self::Measurement(input.parse()?))
+        } else if lookahead.peek(kw::rename) {
+            let kw = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::Rename(kw, input.parse()?))
+        } else if lookahead.peek(kw::flatten) {
+            Ok(Self::Flatten(input.parse()?))
+        } else if lookahead.peek(kw::prefix) {
+            let kw = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::Prefix(kw, input.parse()?))
+        } else if lookahead.peek(kw::with) {
+            let kw = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::With(kw, input.parse()?))
+        } else if lookahead.peek(kw::serialize_with) {
+            let kw = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::SerializeWith(kw, input.parse()?))
         } else {
             Err(lookahead.error())
         }
@@ -56,6 +117,173 @@ impl Parse for FieldAttrs {
     }
 }
 
+enum StructAttr {
+    Measurement(LitStr),
+    RenameAll(LitStr),
+    VariantTag(LitStr),
+    Tag(StaticTag),
+    DefaultTags(Path),
+    /// The keyword is kept to point errors at it
+    Select(kw::select),
+}
+
+/// A tag with a constant value, `tag(name = "...", value = "...")`
+struct StaticTag {
+    name: LitStr,
+    value: LitStr,
+}
+
+impl Parse for StaticTag {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let content;
+        let parens = parenthesized!(content in input);
+        let mut name = None;
+        let mut value = None;
+        while !content.is_empty() {
+            let lookahead = content.lookahead1();
+            if lookahead.peek(kw::name) {
+                content.parse::<kw::name>()?;
+                content.parse::<Token![=]>()?;
+                name = Some(content.parse()?);
+            } else if lookahead.peek(kw::value) {
+                content.parse::<kw::value>()?;
+                content.parse::<Token![=]>()?;
+                value = Some(content.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+        match (name, value) {
+            (Some(name), Some(value)) => Ok(Self { name, value }),
+            _ => Err(syn::Error::new(
+                parens.span.join(),
+                "expected tag(name = \"...\", value = \"...\")",
+            )),
+        }
+    }
+}
+
+impl Parse for StructAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::measurement) {
+            input.parse::<kw::measurement>()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::Measurement(input.parse()?))
+        } else if lookahead.peek(kw::rename_all) {
+            input.parse::<kw::rename_all>()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::RenameAll(input.parse()?))
+        } else if lookahead.peek(kw::variant_tag) {
+            input.parse::<kw::variant_tag>()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::VariantTag(input.parse()?))
+        } else if lookahead.peek(kw::tag) {
+            input.parse::<kw::tag>()?;
+            Ok(Self::Tag(input.parse()?))
+        } else if lookahead.peek(kw::default_tags) {
+            input.parse::<kw::default_tags>()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::DefaultTags(input.parse()?))
+        } else if lookahead.peek(kw::select) {
+            Ok(Self::Select(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+struct StructAttrs(Punctuated<StructAttr, Token![,]>);
+
+impl Parse for StructAttrs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        Ok(Self(Punctuated::parse_terminated(input)?))
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct WriteableStruct {
+    measurement: Option<LitStr>,
+    pub(crate) rename_all: RenameRule,
+    pub(crate) variant_tag: Option<LitStr>,
+    /// Tags added to every point
+    tags: Vec<StaticTag>,
+    /// A function returning tags added to every point
+    default_tags: Option<Path>,
+    /// Whether to generate a typed `SELECT` query builder, see [`select_impl`]
+    select: Option<kw::select>,
+}
+
+impl WriteableStruct {
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut strukt = Self::default();
+        for attr in attrs {
+            match &attr.meta {
+                Meta::List(list) if list.path.is_ident("influxdb") => {
+                    for attr in syn::parse2::<StructAttrs>(list.tokens.clone())?.0 {
+                        match attr {
+                            StructAttr::Measurement(lit) => strukt.measurement = Some(lit),
+                            StructAttr::RenameAll(lit) => {
+                                strukt.rename_all = RenameRule::from_lit(&lit)?
+                            }
+                            StructAttr::VariantTag(lit) => strukt.variant_tag = Some(lit),
+                            StructAttr::Tag(tag) => strukt.tags.push(tag),
+                            StructAttr::DefaultTags(path) => strukt.default_tags = Some(path),
+                            StructAttr::Select(kw) => strukt.select = Some(kw),
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(strukt)
+    }
+}
+
+pub(crate) enum VariantAttr {
+    Rename(LitStr),
+}
+
+impl Parse for VariantAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::rename) {
+            input.parse::<kw::rename>()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::Rename(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+/// Returns the name of an enum variant, honoring `#[influxdb(rename = "...")]`
+pub(crate) fn variant_name(
+    attrs: &[Attribute],
+    ident: &Ident,
+    rename_all: RenameRule,
+) -> syn::Result<String> {
+    let mut name = rename_all.apply_to_variant(&ident.unraw().to_string());
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(list) if list.path.is_ident("influxdb") => {
+                let attrs =
+                    list.parse_args_with(Punctuated::<VariantAttr, Token![,]>::parse_terminated)?;
+                for attr in attrs {
+                    match attr {
+                        VariantAttr::Rename(lit) => name = lit.value(),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(name)
+}
+
 impl TryFrom<Field> for WriteableField {
     type Error = syn::Error;
 
@@ -70,6 +298,11 @@ impl TryFrom<Field> for WriteableField {
         let mut has_time_attr = false;
         let mut is_tag = false;
         let mut is_ignore = false;
+        let mut is_measurement = false;
+        let mut rename = None;
+        let mut is_flatten = false;
+        let mut prefix = None;
+        let mut serialize_with: Option<(LitStr, Path)> = None;
 
         for attr in field.attrs {
             match attr.meta {
@@ -79,6 +312,27 @@ impl TryFrom<Field> for WriteableField {
                             FieldAttr::Time(_) => has_time_attr = true,
                             FieldAttr::Tag(_) => is_tag = true,
                             FieldAttr::Ignore(_) => is_ignore = true,
+                            FieldAttr::Measurement(_) => is_measurement = true,
+                            FieldAttr::Rename(_, lit) => rename = Some(lit),
+                            FieldAttr::Flatten(_) => is_flatten = true,
+                            FieldAttr::Prefix(_, lit) => prefix = Some(lit),
+                            FieldAttr::With(_, lit) | FieldAttr::SerializeWith(_, lit)
+                                if serialize_with.is_some() =>
+                            {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "only one of with or serialize_with can be used",
+                                ));
+                            }
+                            FieldAttr::With(_, lit) => {
+                                let mut path = lit.parse::<Path>()?;
+                                path.segments.push(format_ident!("serialize").into());
+                                serialize_with = Some((lit, path));
+                            }
+                            FieldAttr::SerializeWith(_, lit) => {
+                                let path = lit.parse()?;
+                                serialize_with = Some((lit, path));
+                            }
                         }
                     }
                 }
@@ -86,94 +340,365 @@ impl TryFrom<Field> for WriteableField {
             }
         }
 
-        if [has_time_attr, is_tag, is_ignore]
+        if [has_time_attr, is_tag, is_ignore, is_measurement, is_flatten]
             .iter()
             .filter(|&&b| b)
             .count()
             > 1
         {
-            panic!("only one of time, tag, or ignore can be used");
+            return Err(syn::Error::new_spanned(
+                &ident,
+                "only one of time, tag, ignore, measurement, or flatten can be used",
+            ));
+        }
+        if let Some(prefix) = &prefix {
+            if !is_flatten {
+                return Err(syn::Error::new_spanned(
+                    prefix,
+                    "prefix can only be used on flatten fields",
+                ));
+            }
         }
 
         // A field is considered a time field if:
         // 1. It has the #[influxdb(time)] attribute, OR
         // 2. It's named "time" and doesn't have #[influxdb(ignore)]
-        let is_time = has_time_attr || (ident == "time" && !is_ignore);
+        let is_time =
+            has_time_attr || (ident == "time" && !is_ignore && !is_measurement && !is_flatten);
+        if let Some((lit, _)) = &serialize_with {
+            if has_time_attr || is_ignore || is_measurement || is_flatten || ident == "time" {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "with and serialize_with can only be used on tags and fields",
+                ));
+            }
+        }
+        let flatten = is_flatten.then(|| prefix.unwrap_or_else(|| LitStr::new("", ident.span())));
 
         Ok(WriteableField {
             ident,
             ty,
+            rename,
             is_time,
             is_tag,
             is_ignore,
+            is_measurement,
+            flatten,
+            serialize_with: serialize_with.map(|(_, path)| path),
         })
     }
 }
 
-pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
-    let ident = input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-
-    let fields = match input.data {
-        Data::Struct(strukt) => strukt.fields,
-        Data::Enum(inum) => {
-            return Err(syn::Error::new(
-                inum.enum_token.span,
-                "#[derive(InfluxDbWriteable)] can only be used on structs",
-            ))
-        }
-        Data::Union(cdu) => {
-            return Err(syn::Error::new(
-                cdu.union_token.span,
-                "#[derive(InfluxDbWriteable)] can only be used on structs",
-            ))
-        }
-    };
+/// The code generated for the fields of a struct or an enum variant
+struct FieldsExpansion {
+    /// Field patterns binding every used field to a local variable
+    pattern: Vec<TokenStream>,
+    time_ty: Type,
+    /// Builds a `WriteQuery` named `name` from the bound variables
+    query_body: TokenStream,
+    /// Builds a `Point` named `name` from the bound variables, which are references
+    point_body: TokenStream,
+    measurement_binding: Option<Ident>,
+    /// Expressions adding the keys of the tags and fields to `__influxdb_keys`
+    keys: Vec<TokenStream>,
+    /// The bounds needed to borrow the values of the point, see [`add_point_bounds`]
+    point_bounds: Vec<WherePredicate>,
+}
 
-    let writeable_fields: Vec<WriteableField> = match fields {
-        Fields::Named(fields) => fields
+/// Parses the named fields of the struct or enum variant `owner`
+pub(crate) fn parse_fields(owner: &Ident, fields: Fields) -> syn::Result<Vec<WriteableField>> {
+    match fields {
+        Fields::Named(fields) if !fields.named.is_empty() => fields
             .named
             .into_iter()
             .map(WriteableField::try_from)
-            .collect::<syn::Result<Vec<_>>>()?,
-        _ => panic!("A struct without named fields is not supported!"),
+            .collect::<syn::Result<Vec<_>>>(),
+        Fields::Named(_) | Fields::Unit => Err(syn::Error::new_spanned(
+            owner,
+            format!("`{owner}` does not have any fields"),
+        )),
+        Fields::Unnamed(fields) => Err(syn::Error::new_spanned(
+            fields,
+            "tuple structs and variants are not supported, use named fields",
+        )),
+    }
+}
+
+/// The tags and fields of a struct or enum variant, added one at a time
+struct Assignments {
+    /// Field patterns binding every used field to a local variable
+    pattern: Vec<TokenStream>,
+    time: Option<(Ident, Type)>,
+    measurement_binding: Option<Ident>,
+    /// Expressions adding a tag or field to `query`
+    query: Vec<TokenStream>,
+    /// Expressions adding a tag or field to `point`, from bindings that are references
+    point: Vec<TokenStream>,
+    /// Expressions adding the keys of the tags and fields to `__influxdb_keys`
+    keys: Vec<TokenStream>,
+    /// Whether the keys are prepended with `__influxdb_prefix` through `__influxdb_key`
+    uses_key_fn: bool,
+    /// The bounds needed to borrow the values of the point, see [`add_point_bounds`]
+    point_bounds: Vec<WherePredicate>,
+}
+
+fn assignments(
+    fields: &[WriteableField],
+    rename_all: RenameRule,
+    strukt: Option<&WriteableStruct>,
+    variant_tag: Option<(&LitStr, &str)>,
+    prefixed: bool,
+) -> syn::Result<Assignments> {
+    let mut assignments = Assignments {
+        pattern: Vec::new(),
+        time: None,
+        measurement_binding: None,
+        query: Vec::new(),
+        point: Vec::new(),
+        keys: Vec::new(),
+        uses_key_fn: false,
+        point_bounds: Vec::new(),
+    };
+
+    // The keys of all tags and fields, flattened fields are only known at runtime
+    let mut keys = HashSet::new();
+
+    for StaticTag { name, value } in strukt.map_or(&[][..], |strukt| &strukt.tags) {
+        if !keys.insert(name.value()) {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("multiple tags or fields are named `{}`", name.value()),
+            ));
+        }
+        assignments.query.push(quote!(query.add_tag(#name, #value)));
+        assignments.point.push(quote!(point.add_tag(#name, #value)));
+    }
+
+    if let Some(default_tags) = strukt.and_then(|strukt| strukt.default_tags.as_ref()) {
+        assignments.query.push(quote! {{
+            let mut query = query;
+            for (key, value) in #default_tags() {
+                query = query.add_tag(key, value);
+            }
+            query
+        }});
+        assignments.point.push(quote! {{
+            let mut point = point;
+            for (key, value) in #default_tags() {
+                point = point.add_tag(key, value);
+            }
+            point
+        }});
+    }
+
+    if let Some((tag, value)) = variant_tag {
+        keys.insert(tag.value());
+        assignments.query.push(quote!(query.add_tag(#tag, #value)));
+        assignments.point.push(quote!(point.add_tag(#tag, #value)));
+        assignments
+            .keys
+            .push(quote!(__influxdb_keys.push(::std::string::String::from(#tag))));
+    }
+
+    // Conversion errors are wrapped by the derived error type, or returned as is from
+    // `InfluxDbFields`
+    let map_err = match prefixed {
+        true => quote!(?),
+        false => quote!(.map_err(__influxdb_private::Error::ConversionError)?),
     };
 
-    // Find the time field
-    let mut time_field = None;
-    let mut time_field_ty = None;
-    for wf in &writeable_fields {
+    for (idx, wf) in fields.iter().enumerate() {
+        if wf.is_ignore {
+            continue;
+        }
+
+        // Bind every field to a generated name so that fields can't shadow our variables
+        let ident = &wf.ident;
+        let binding = format_ident!("__influxdb_field{}", idx);
+        assignments.pattern.push(quote!(#ident: #binding));
+
         if wf.is_time {
-            if time_field.is_some() {
-                panic!("multiple time fields found!");
+            if assignments.time.is_some() {
+                return Err(syn::Error::new_spanned(ident, "multiple time fields found"));
+            }
+            assignments.time = Some((binding, wf.ty.clone()));
+        } else if wf.is_measurement {
+            if assignments.measurement_binding.is_some() {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "multiple measurement fields found",
+                ));
+            }
+            assignments.measurement_binding = Some(binding);
+            let ty = &wf.ty;
+            assignments.point_bounds.push(syn::parse_quote! {
+                for<'__influxdb_point> &'__influxdb_point #ty: ::core::convert::AsRef<str>
+            });
+        } else if let Some(prefix) = &wf.flatten {
+            let prefix = match prefixed {
+                true => quote!(&::std::format!("{}{}", __influxdb_prefix, #prefix)),
+                false => quote!(#prefix),
+            };
+            assignments.query.push(quote! {
+                ::influxdb::InfluxDbFields::add_to_query(#binding, query, #prefix) #map_err
+            });
+            assignments.point.push(quote! {
+                ::influxdb::InfluxDbFields::add_to_point(#binding, point, #prefix) #map_err
+            });
+            let ty = &wf.ty;
+            assignments.keys.push(quote! {
+                __influxdb_keys.extend(<#ty as ::influxdb::InfluxDbFields>::keys(#prefix))
+            });
+        } else {
+            let key = wf.key(rename_all);
+            if !keys.insert(key.clone()) {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!("multiple tags or fields are named `{key}`"),
+                ));
+            }
+            let key = match prefixed {
+                true => {
+                    assignments.uses_key_fn = true;
+                    quote!(__influxdb_key(#key))
+                }
+                false => quote!(#key),
+            };
+            assignments
+                .keys
+                .push(quote!(__influxdb_keys.push(::std::string::String::from(#key))));
+            // The point is built from references to the fields
+            let (query_value, point_value) = match &wf.serialize_with {
+                Some(path) => (
+                    quote!(#path(&#binding).map_err(::influxdb::ConversionError::new) #map_err),
+                    quote!(#path(#binding).map_err(::influxdb::ConversionError::new) #map_err),
+                ),
+                None => {
+                    let ty = &wf.ty;
+                    let bound = syn::parse_quote! {
+                        for<'__influxdb_point> &'__influxdb_point #ty:
+                            ::influxdb::PointWriteType<'__influxdb_point>
+                    };
+                    if !assignments.point_bounds.contains(&bound) {
+                        assignments.point_bounds.push(bound);
+                    }
+                    (quote!(#binding), quote!(#binding))
+                }
+            };
+            match wf.is_tag {
+                true => {
+                    assignments
+                        .query
+                        .push(quote!(query.add_tag(#key, #query_value)));
+                    assignments
+                        .point
+                        .push(quote!(point.add_tag(#key, #point_value)));
+                }
+                false => {
+                    assignments
+                        .query
+                        .push(quote!(query.add_field(#key, #query_value)));
+                    assignments
+                        .point
+                        .push(quote!(point.add_field(#key, #point_value)));
+                }
             }
-            time_field = Some(wf.ident.clone());
-            time_field_ty = Some(wf.ty.clone());
         }
     }
 
+    Ok(assignments)
+}
+
+/// Expands the fields of the struct or enum variant `owner`
+fn expand_fields(
+    owner: &Ident,
+    fields: &[WriteableField],
+    strukt: &WriteableStruct,
+    variant_tag: Option<(&LitStr, &str)>,
+) -> syn::Result<FieldsExpansion> {
+    let Assignments {
+        pattern,
+        time,
+        measurement_binding,
+        query: query_assignments,
+        point: point_assignments,
+        keys,
+        point_bounds,
+        ..
+    } = assignments(fields, strukt.rename_all, Some(strukt), variant_tag, false)?;
+
     // There must be exactly one time field
-    let time_field = time_field.expect("no time field found");
-    let time_field_ty = time_field_ty.unwrap();
-
-    // Generate field assignments (excluding time and ignored fields)
-    let field_assignments = writeable_fields
-        .into_iter()
-        .filter_map(|wf| {
-            if wf.is_ignore || wf.is_time {
-                None
-            } else {
-                let ident = wf.ident;
-                Some(match wf.is_tag {
-                    true => quote!(query.add_tag(stringify!(#ident), self.#ident)),
-                    false => quote!(query.add_field(stringify!(#ident), self.#ident)),
-                })
-            }
-        })
-        .collect::<Vec<_>>();
+    let Some((time_binding, time_ty)) = time else {
+        return Err(syn::Error::new_spanned(
+            owner,
+            "no time field found, add a field named `time` or mark one with #[influxdb(time)]",
+        ));
+    };
 
-    // Add a necessary where clause
-    let mut where_clause = where_clause.cloned().unwrap_or(WhereClause {
+    // The measurement field takes precedence over the name passed in
+    let (query_measurement, point_measurement) = match &measurement_binding {
+        Some(binding) => (
+            quote! {
+                let _ = name;
+                let name = #binding;
+            },
+            quote! {
+                let _ = name;
+                let name = ::core::convert::AsRef::<str>::as_ref(#binding);
+            },
+        ),
+        None => (quote!(), quote!()),
+    };
+
+    let query_body = quote! {
+        #query_measurement
+        let timestamp: ::influxdb::Timestamp = ::core::convert::TryInto::try_into(#time_binding)
+            .map_err(__influxdb_private::Error::TimestampError)?;
+        let mut query = ::influxdb::InfluxDbWriteable::try_into_query(timestamp, name)
+            .map_err(__influxdb_private::Error::QueryError)?;
+        #(
+            query = #query_assignments;
+        )*
+        Ok(query)
+    };
+
+    // Converting a borrowed value into a point needs to clone the time field
+    let point_body = quote! {
+        #point_measurement
+        let timestamp: ::influxdb::Timestamp = ::core::convert::TryInto::try_into(
+            ::core::clone::Clone::clone(#time_binding)
+        )
+        .map_err(__influxdb_private::Error::TimestampError)?;
+        let mut point = ::influxdb::Point::new(timestamp, name);
+        #(
+            point = #point_assignments;
+        )*
+        Ok(point)
+    };
+
+    Ok(FieldsExpansion {
+        pattern,
+        time_ty,
+        query_body,
+        point_body,
+        measurement_binding,
+        keys,
+        point_bounds,
+    })
+}
+
+/// Requires that the point built from a reference can borrow the values of the measurement,
+/// tags and fields.
+///
+/// The bounds are higher-ranked, so that the `ToPoint` impl is left out instead of failing to
+/// compile if a field type only converts into `Type` by value.
+fn add_point_bounds(where_clause: &mut WhereClause, bounds: &[WherePredicate]) {
+    where_clause.predicates.extend(bounds.iter().cloned());
+}
+
+/// Adds the where clause predicates required by the generated error type
+fn where_clause_for(generics: &Generics, time_ty: &Type) -> WhereClause {
+    let mut where_clause = generics.where_clause.clone().unwrap_or(WhereClause {
         where_token: Default::default(),
         predicates: Punctuated::new(),
     });
@@ -187,7 +712,7 @@ pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
         .arguments = PathArguments::AngleBracketed(AngleBracketedGenericArguments {
         colon2_token: None,
         lt_token: Default::default(),
-        args: [GenericArgument::Type(time_field_ty.clone())]
+        args: [GenericArgument::Type(time_ty.clone())]
             .into_iter()
             .collect(),
         gt_token: Default::default(),
@@ -205,12 +730,552 @@ pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
             .into_iter()
             .collect(),
         }));
+    where_clause
+}
+
+/// Generates `MEASUREMENT` and `into_write_query` for a fixed measurement name
+fn static_measurement_impl(measurement: &LitStr) -> TokenStream {
+    quote! {
+        /// The measurement points of this type are written to
+        pub const MEASUREMENT: &'static str = #measurement;
+
+        /// Converts this value into a [`WriteQuery`](::influxdb::WriteQuery) for
+        /// [`Self::MEASUREMENT`].
+        pub fn into_write_query(
+            self
+        ) -> ::core::result::Result<
+            ::influxdb::WriteQuery,
+            <Self as ::influxdb::InfluxDbWriteable>::Error
+        > {
+            ::influxdb::InfluxDbWriteable::try_into_query(self, Self::MEASUREMENT)
+        }
+    }
+}
+
+/// Generates `into_write_query` for a measurement name stored in the value, which replaces the
+/// name passed to `try_into_query`
+fn dynamic_measurement_impl() -> TokenStream {
+    quote! {
+        /// Converts this value into a [`WriteQuery`](::influxdb::WriteQuery) for the
+        /// measurement stored in this value.
+        pub fn into_write_query(
+            self
+        ) -> ::core::result::Result<
+            ::influxdb::WriteQuery,
+            <Self as ::influxdb::InfluxDbWriteable>::Error
+        > {
+            ::influxdb::InfluxDbWriteable::try_into_query(self, "")
+        }
+    }
+}
+
+/// The bodies of the generated trait methods for a struct or enum
+struct Expansion {
+    time_ty: Type,
+    query: TokenStream,
+    point: TokenStream,
+    measurement_impl: Option<TokenStream>,
+    /// The bodies of the `InfluxDbFields` methods, for structs
+    fields_bodies: Option<FieldsBodies>,
+    /// Items generated outside of the private scope
+    public_items: Option<TokenStream>,
+    /// The bounds needed to borrow the values of the point, see [`add_point_bounds`]
+    point_bounds: Vec<WherePredicate>,
+}
+
+fn expand_struct(
+    strukt: &WriteableStruct,
+    input: &DeriveInput,
+    fields: Fields,
+) -> syn::Result<Expansion> {
+    let ident = &input.ident;
+    if let Some(variant_tag) = &strukt.variant_tag {
+        return Err(syn::Error::new_spanned(
+            variant_tag,
+            "variant_tag can only be used on enums",
+        ));
+    }
+
+    let fields = parse_fields(ident, fields)?;
+    let FieldsExpansion {
+        pattern,
+        time_ty,
+        query_body,
+        point_body,
+        measurement_binding,
+        keys,
+        point_bounds,
+    } = expand_fields(ident, &fields, strukt, None)?;
+    let fields_bodies = fields_bodies(&fields, strukt.rename_all)?;
+
+    let measurement_impl = match (&strukt.measurement, measurement_binding) {
+        (Some(_), Some(_)) => {
+            let wf = fields.iter().find(|wf| wf.is_measurement).unwrap();
+            return Err(syn::Error::new_spanned(
+                &wf.ident,
+                "measurement cannot be both static and read from a field",
+            ));
+        }
+        (Some(measurement), None) => Some(static_measurement_impl(measurement)),
+        (None, Some(_)) => Some(dynamic_measurement_impl()),
+        (None, None) => None,
+    };
+
+    Ok(Expansion {
+        time_ty,
+        query: quote! {
+            let Self { #(#pattern,)* .. } = self;
+            #query_body
+        },
+        point: quote! {
+            let Self { #(#pattern,)* .. } = self;
+            #point_body
+        },
+        measurement_impl,
+        fields_bodies: Some(fields_bodies),
+        public_items: select_impl(strukt, input, &fields, &keys)?,
+        point_bounds,
+    })
+}
+
+fn expand_enum(strukt: &WriteableStruct, ident: &Ident, inum: DataEnum) -> syn::Result<Expansion> {
+    if let Some(select) = &strukt.select {
+        return Err(syn::Error::new_spanned(
+            select,
+            "select can only be used on structs",
+        ));
+    }
+    if strukt.variant_tag.is_none() {
+        if let Some(measurement) = &strukt.measurement {
+            return Err(syn::Error::new_spanned(
+                measurement,
+                "enums without variant_tag use the variant name as measurement, \
+                 set variant_tag to write the variant name as a tag instead",
+            ));
+        }
+    }
+
+    let mut time_ty: Option<Type> = None;
+    let mut query_arms = Vec::new();
+    let mut point_arms = Vec::new();
+    let mut point_bounds = Vec::new();
+
+    for variant in inum.variants {
+        let variant_ident = &variant.ident;
+        let name = variant_name(&variant.attrs, variant_ident, strukt.rename_all)?;
+        let fields = parse_fields(variant_ident, variant.fields)?;
+        if let Some(wf) = fields.iter().find(|wf| wf.is_measurement) {
+            return Err(syn::Error::new_spanned(
+                &wf.ident,
+                "the measurement of an enum is determined by its variants",
+            ));
+        }
+
+        let FieldsExpansion {
+            pattern,
+            time_ty: variant_time_ty,
+            query_body,
+            point_body,
+            point_bounds: variant_point_bounds,
+            ..
+        } = expand_fields(
+            variant_ident,
+            &fields,
+            strukt,
+            strukt.variant_tag.as_ref().map(|tag| (tag, name.as_str())),
+        )?;
+
+        // The generated error type is generic over a single time type
+        match &time_ty {
+            Some(time_ty) if *time_ty != variant_time_ty => {
+                return Err(syn::Error::new_spanned(
+                    &variant_time_ty,
+                    format!(
+                        "all variants must use the same time type, expected `{}`",
+                        quote!(#time_ty)
+                    ),
+                ))
+            }
+            Some(_) => {}
+            None => time_ty = Some(variant_time_ty),
+        }
+        for bound in variant_point_bounds {
+            if !point_bounds.contains(&bound) {
+                point_bounds.push(bound);
+            }
+        }
+
+        // Without a variant tag, the variant name replaces the name passed in, so that the
+        // variants can be told apart
+        let measurement = strukt.variant_tag.is_none().then(|| {
+            quote! {
+                let _ = name;
+                let name = #name;
+            }
+        });
+        query_arms.push(quote! {
+            Self::#variant_ident { #(#pattern,)* .. } => {
+                #measurement
+                #query_body
+            }
+        });
+        point_arms.push(quote! {
+            Self::#variant_ident { #(#pattern,)* .. } => {
+                #measurement
+                #point_body
+            }
+        });
+    }
+
+    let Some(time_ty) = time_ty else {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "#[derive(InfluxDbWriteable)] cannot be used on enums without variants",
+        ));
+    };
+
+    let measurement_impl = match (&strukt.variant_tag, &strukt.measurement) {
+        (None, _) => Some(dynamic_measurement_impl()),
+        (Some(_), Some(measurement)) => Some(static_measurement_impl(measurement)),
+        (Some(_), None) => None,
+    };
+
+    Ok(Expansion {
+        time_ty,
+        query: quote! {
+            match self {
+                #(#query_arms)*
+            }
+        },
+        point: quote! {
+            match self {
+                #(#point_arms)*
+            }
+        },
+        measurement_impl,
+        fields_bodies: None,
+        public_items: None,
+        point_bounds,
+    })
+}
+
+/// Delegates to the only field of a newtype struct
+fn expand_newtype(
+    strukt: &WriteableStruct,
+    input: &DeriveInput,
+    inner_ty: &Type,
+) -> syn::Result<TokenStream> {
+    if let Some(variant_tag) = &strukt.variant_tag {
+        return Err(syn::Error::new_spanned(
+            variant_tag,
+            "variant_tag can only be used on enums",
+        ));
+    }
+    if let Some(select) = &strukt.select {
+        return Err(syn::Error::new_spanned(
+            select,
+            "select can only be used on structs with named fields",
+        ));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut where_clause = where_clause.cloned().unwrap_or(WhereClause {
+        where_token: Default::default(),
+        predicates: Punctuated::new(),
+    });
+    let mut writeable_where_clause = where_clause.clone();
+    writeable_where_clause
+        .predicates
+        .push(syn::parse_quote!(#inner_ty: ::influxdb::InfluxDbWriteable));
+    // Higher-ranked like the bounds of `add_point_bounds`, so that the `ToPoint` impls are left
+    // out if the inner type doesn't implement `ToPoint`
+    where_clause.predicates.push(syn::parse_quote! {
+        for<'__influxdb_point> #inner_ty: ::influxdb::ToPoint
+    });
+    let borrowed_impl = borrowed_impl(
+        input,
+        &where_clause,
+        quote!(<#inner_ty as ::influxdb::ToPoint>::Error),
+    );
+    let measurement_impl = strukt.measurement.as_ref().map(|measurement| {
+        let measurement_impl = static_measurement_impl(measurement);
+        quote! {
+            impl #impl_generics #ident #ty_generics #writeable_where_clause {
+                #measurement_impl
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::influxdb::InfluxDbWriteable for #ident #ty_generics #writeable_where_clause {
+            type Error = <#inner_ty as ::influxdb::InfluxDbWriteable>::Error;
+
+            fn try_into_query<I: Into<String>>(
+                self,
+                name: I
+            ) -> ::core::result::Result<::influxdb::WriteQuery, Self::Error> {
+                ::influxdb::InfluxDbWriteable::try_into_query(self.0, name)
+            }
+        }
+
+        #measurement_impl
+
+        impl #impl_generics ::influxdb::ToPoint for #ident #ty_generics #where_clause {
+            type Error = <#inner_ty as ::influxdb::ToPoint>::Error;
+
+            fn to_point<'a, I: Into<::std::borrow::Cow<'a, str>>>(
+                &'a self,
+                name: I
+            ) -> ::core::result::Result<::influxdb::Point<'a>, Self::Error> {
+                ::influxdb::ToPoint::to_point(&self.0, name)
+            }
+        }
+
+        #borrowed_impl
+    })
+}
+
+/// Implements `InfluxDbWriteable` for references through `ToPoint`, so that writing a value
+/// doesn't consume it
+fn borrowed_impl(
+    input: &DeriveInput,
+    where_clause: &WhereClause,
+    error_ty: TokenStream,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let generics = with_point_lifetime(&input.generics);
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::influxdb::InfluxDbWriteable for &'__influxdb #ident #ty_generics
+            #where_clause
+        {
+            type Error = #error_ty;
+
+            fn try_into_query<I: Into<String>>(
+                self,
+                name: I
+            ) -> ::core::result::Result<::influxdb::WriteQuery, Self::Error> {
+                ::influxdb::ToPoint::to_point(self, name.into()).map(::influxdb::WriteQuery::from)
+            }
+        }
+    }
+}
+
+/// Generates `select_from`, `select` and a query builder with a `where_<field>` method for
+/// every tag and field, if requested with `#[influxdb(select)]`
+fn select_impl(
+    strukt: &WriteableStruct,
+    input: &DeriveInput,
+    fields: &[WriteableField],
+    keys: &[TokenStream],
+) -> syn::Result<Option<TokenStream>> {
+    let Some(select) = &strukt.select else {
+        return Ok(None);
+    };
+    // The query builder can't be generic
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            select,
+            "select can only be used on structs without generics",
+        ));
+    }
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let select_ident = format_ident!("{}Select", ident);
+    let select_doc = format!(
+        "A typed `SELECT` query for [`{ident}`], created by [`{ident}::select_from`].\n\n\
+         Use [`into_query`]({select_ident}::into_query) to get the underlying \
+         [`SelectQuery`](::influxdb::SelectQuery)."
+    );
+
+    let where_fns = fields
+        .iter()
+        .filter(|wf| !(wf.is_ignore || wf.is_time || wf.is_measurement || wf.flatten.is_some()))
+        .map(|wf| {
+            let key = wf.key(strukt.rename_all);
+            let where_ident = format_ident!("where_{}", wf.ident.unraw());
+            let where_doc = format!("Only selects points where `{key}` equals `value`");
+            let condition = match wf.is_tag {
+                true => quote!(where_tag),
+                false => quote!(where_field),
+            };
+            quote! {
+                #[doc = #where_doc]
+                #[must_use = "Creating a query is pointless unless you execute it"]
+                pub fn #where_ident<V: ::core::convert::Into<::influxdb::Type>>(self, value: V) -> Self {
+                    Self(self.0.#condition(#key, value))
+                }
+            }
+        });
+
+    let select_fn = strukt.measurement.as_ref().map(|_| {
+        quote! {
+            /// Creates a typed query selecting the tags and fields of this type from
+            /// [`Self::MEASUREMENT`].
+            pub fn select() -> #select_ident {
+                Self::select_from(Self::MEASUREMENT)
+            }
+        }
+    });
+
+    Ok(Some(quote! {
+        #[doc = #select_doc]
+        #vis struct #select_ident(::influxdb::SelectQuery<#ident>);
+
+        impl #ident {
+            /// Creates a typed query selecting the tags and fields of this type from
+            /// `measurement`.
+            #[allow(unused_mut)]
+            pub fn select_from<I: ::core::convert::Into<::std::string::String>>(
+                measurement: I
+            ) -> #select_ident {
+                let mut __influxdb_keys = ::std::vec::Vec::<::std::string::String>::new();
+                #(#keys;)*
+                #select_ident(::influxdb::SelectQuery::new(measurement, __influxdb_keys))
+            }
+
+            #select_fn
+        }
+
+        impl #select_ident {
+            #(#where_fns)*
+
+            /// Only selects points with a timestamp in `start..end`
+            #[must_use = "Creating a query is pointless unless you execute it"]
+            pub fn between(self, start: ::influxdb::Timestamp, end: ::influxdb::Timestamp) -> Self {
+                Self(self.0.between(start, end))
+            }
+
+            /// Returns the most recent points first
+            #[must_use = "Creating a query is pointless unless you execute it"]
+            pub fn descending(self) -> Self {
+                Self(self.0.descending())
+            }
+
+            /// Returns at most `limit` points per series
+            #[must_use = "Creating a query is pointless unless you execute it"]
+            pub fn limit(self, limit: u64) -> Self {
+                Self(self.0.limit(limit))
+            }
+
+            /// Returns times as integers counted in `precision`
+            #[must_use = "Creating a query is pointless unless you execute it"]
+            pub fn with_epoch(self, precision: ::influxdb::Precision) -> Self {
+                Self(self.0.with_epoch(precision))
+            }
+
+            /// Returns the underlying [`SelectQuery`](::influxdb::SelectQuery)
+            pub fn into_query(self) -> ::influxdb::SelectQuery<#ident> {
+                self.0
+            }
+        }
+
+        impl ::core::convert::From<#select_ident> for ::influxdb::SelectQuery<#ident> {
+            fn from(query: #select_ident) -> Self {
+                query.0
+            }
+        }
+
+        impl ::core::convert::TryFrom<#select_ident> for ::influxdb::ReadQuery {
+            type Error = ::influxdb::Error;
+
+            fn try_from(
+                query: #select_ident
+            ) -> ::core::result::Result<Self, Self::Error> {
+                ::core::convert::TryFrom::try_from(query.0)
+            }
+        }
+
+        impl ::influxdb::Query for #select_ident {
+            fn build(&self) -> ::core::result::Result<::influxdb::ValidQuery, ::influxdb::Error> {
+                ::influxdb::Query::build(&self.0)
+            }
+
+            fn build_with_opts(
+                &self,
+                use_v2: bool
+            ) -> ::core::result::Result<::influxdb::ValidQuery, ::influxdb::Error> {
+                ::influxdb::Query::build_with_opts(&self.0, use_v2)
+            }
+
+            fn get_type(&self) -> ::influxdb::QueryType {
+                ::influxdb::Query::get_type(&self.0)
+            }
+
+            fn get_epoch(&self) -> ::core::option::Option<::influxdb::Precision> {
+                ::influxdb::Query::get_epoch(&self.0)
+            }
+        }
+    }))
+}
+
+pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
+    let strukt = WriteableStruct::from_attrs(&input.attrs)?;
+
+    let Expansion {
+        time_ty,
+        query,
+        point,
+        measurement_impl,
+        fields_bodies,
+        public_items,
+        point_bounds,
+    } = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                return expand_newtype(&strukt, &input, &fields.unnamed[0].ty);
+            }
+            Fields::Unnamed(fields) => return Err(syn::Error::new_spanned(
+                fields,
+                "#[derive(InfluxDbWriteable)] only supports tuple structs with exactly one field",
+            )),
+            _ => expand_struct(&strukt, &input, data.fields.clone())?,
+        },
+        Data::Enum(inum) => expand_enum(&strukt, &input.ident, inum.clone())?,
+        Data::Union(cdu) => {
+            return Err(syn::Error::new(
+                cdu.union_token.span,
+                "#[derive(InfluxDbWriteable)] can only be used on structs and enums",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = where_clause_for(&input.generics, &time_ty);
+
+    // Converting a borrowed value into a point needs to clone the time field
+    let mut point_where_clause = where_clause.clone();
+    point_where_clause
+        .predicates
+        .push(syn::parse_quote!(#time_ty: ::core::clone::Clone));
+    add_point_bounds(&mut point_where_clause, &point_bounds);
+
+    let measurement_impl = measurement_impl.map(|measurement_impl| {
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #measurement_impl
+            }
+        }
+    });
+
+    let fields_impl = fields_bodies.map(|bodies| fields_impl(&input, bodies));
+    let borrowed_impl = borrowed_impl(
+        &input,
+        &point_where_clause,
+        quote!(__influxdb_private::Error<#time_ty>),
+    );
 
     // Assemble the rest of the code
     Ok(quote! {
         const _: () = {
             mod __influxdb_private {
                 use ::influxdb::{InfluxDbWriteable, Timestamp};
+                use ::core::convert::TryFrom;
                 use ::core::fmt::{self, Debug, Display, Formatter, Write as _};
 
                 pub enum Error<T>
@@ -218,7 +1283,8 @@ pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
                     Timestamp: TryFrom<T>
                 {
                     TimestampError(<Timestamp as TryFrom<T>>::Error),
-                    QueryError(<Timestamp as InfluxDbWriteable>::Error)
+                    QueryError(<Timestamp as InfluxDbWriteable>::Error),
+                    ConversionError(::influxdb::ConversionError)
                 }
 
                 impl<T> Clone for Error<T>
@@ -229,7 +1295,8 @@ pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
                     fn clone(&self) -> Self {
                         match self {
                             Self::TimestampError(err) => Self::TimestampError(err.clone()),
-                            Self::QueryError(err) => Self::QueryError(err.clone())
+                            Self::QueryError(err) => Self::QueryError(err.clone()),
+                            Self::ConversionError(err) => Self::ConversionError(err.clone())
                         }
                     }
                 }
@@ -245,6 +1312,9 @@ pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
                                 .field(err)
                                 .finish(),
                             Self::QueryError(err) => f.debug_tuple("QueryError")
+                                .field(err)
+                                .finish(),
+                            Self::ConversionError(err) => f.debug_tuple("ConversionError")
                                 .field(err)
                                 .finish()
                         }
@@ -263,6 +1333,9 @@ pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
                             },
                             Self::QueryError(err) => {
                                 write!(f, "Unable to convert timestamp to query: {err}")
+                            },
+                            Self::ConversionError(err) => {
+                                write!(f, "Unable to convert field value: {err}")
                             }
                         }
                     }
@@ -276,30 +1349,197 @@ pub fn expand_writeable(input: DeriveInput) -> syn::Result<TokenStream> {
                     fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
                         match self {
                             Self::TimestampError(err) => Some(err),
-                            Self::QueryError(err) => Some(err)
+                            Self::QueryError(err) => Some(err),
+                            Self::ConversionError(err) => Some(err)
                         }
                     }
                 }
             }
 
             impl #impl_generics ::influxdb::InfluxDbWriteable for #ident #ty_generics #where_clause {
-                type Error = __influxdb_private::Error<#time_field_ty>;
+                type Error = __influxdb_private::Error<#time_ty>;
 
                 fn try_into_query<I: Into<String>>(
                     self,
                     name: I
                 ) -> ::core::result::Result<::influxdb::WriteQuery, Self::Error> {
-                    let timestamp: ::influxdb::Timestamp = self.#time_field
-                        .try_into()
-                        .map_err(__influxdb_private::Error::TimestampError)?;
-                    let mut query = timestamp.try_into_query(name)
-                        .map_err(__influxdb_private::Error::QueryError)?;
-                    #(
-                        query = #field_assignments;
-                    )*
-                    Ok(query)
+                    #query
+                }
+            }
+
+            #measurement_impl
+
+            impl #impl_generics ::influxdb::ToPoint for #ident #ty_generics #point_where_clause {
+                type Error = __influxdb_private::Error<#time_ty>;
+
+                fn to_point<'a, I: Into<::std::borrow::Cow<'a, str>>>(
+                    &'a self,
+                    name: I
+                ) -> ::core::result::Result<::influxdb::Point<'a>, Self::Error> {
+                    #point
                 }
             }
+
+            #borrowed_impl
         };
+
+        #fields_impl
+
+        #public_items
+    })
+}
+
+/// The bodies of the `InfluxDbFields` methods
+struct FieldsBodies {
+    query: TokenStream,
+    point: TokenStream,
+    keys: TokenStream,
+    /// The bounds needed to borrow the values of the point, see [`add_point_bounds`]
+    point_bounds: Vec<WherePredicate>,
+}
+
+fn fields_bodies(fields: &[WriteableField], rename_all: RenameRule) -> syn::Result<FieldsBodies> {
+    let Assignments {
+        pattern,
+        query: query_assignments,
+        point: point_assignments,
+        keys,
+        uses_key_fn,
+        point_bounds,
+        ..
+    } = assignments(fields, rename_all, None, None, true)?;
+
+    let key_fn = uses_key_fn.then(|| {
+        quote! {
+            let __influxdb_key = |key: &'static str| -> ::std::borrow::Cow<'static, str> {
+                if __influxdb_prefix.is_empty() {
+                    ::std::borrow::Cow::Borrowed(key)
+                } else {
+                    ::std::borrow::Cow::Owned(::std::format!("{}{}", __influxdb_prefix, key))
+                }
+            };
+        }
+    });
+
+    let query_body = quote! {
+        #key_fn
+        let Self { #(#pattern,)* .. } = self;
+        #(
+            query = #query_assignments;
+        )*
+        Ok(query)
+    };
+    let point_body = quote! {
+        #key_fn
+        let Self { #(#pattern,)* .. } = self;
+        #(
+            point = #point_assignments;
+        )*
+        Ok(point)
+    };
+    let keys_body = quote! {
+        #key_fn
+        let mut __influxdb_keys = ::std::vec::Vec::new();
+        #(#keys;)*
+        __influxdb_keys
+    };
+    Ok(FieldsBodies {
+        query: query_body,
+        point: point_body,
+        keys: keys_body,
+        point_bounds,
     })
 }
+
+fn fields_impl(input: &DeriveInput, bodies: FieldsBodies) -> TokenStream {
+    let FieldsBodies {
+        query: query_body,
+        point: point_body,
+        keys: keys_body,
+        point_bounds,
+    } = bodies;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut where_clause = where_clause.cloned().unwrap_or(WhereClause {
+        where_token: Default::default(),
+        predicates: Punctuated::new(),
+    });
+    add_point_bounds(&mut where_clause, &point_bounds);
+
+    quote! {
+        impl #impl_generics ::influxdb::InfluxDbFields for #ident #ty_generics #where_clause {
+            #[allow(unused_mut)]
+            fn add_to_query(
+                self,
+                mut query: ::influxdb::WriteQuery,
+                __influxdb_prefix: &str
+            ) -> ::core::result::Result<::influxdb::WriteQuery, ::influxdb::ConversionError> {
+                #query_body
+            }
+
+            #[allow(unused_mut)]
+            fn add_to_point<'a>(
+                &'a self,
+                mut point: ::influxdb::Point<'a>,
+                __influxdb_prefix: &str
+            ) -> ::core::result::Result<::influxdb::Point<'a>, ::influxdb::ConversionError> {
+                #point_body
+            }
+
+            #[allow(unused_mut)]
+            fn keys(__influxdb_prefix: &str) -> ::std::vec::Vec<::std::string::String> {
+                #keys_body
+            }
+        }
+    }
+}
+
+pub fn expand_fields_derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let strukt = WriteableStruct::from_attrs(&input.attrs)?;
+    if let Some(lit) = strukt.measurement.as_ref().or(strukt.variant_tag.as_ref()) {
+        return Err(syn::Error::new_spanned(
+            lit,
+            "#[derive(InfluxDbFields)] only supports rename_all",
+        ));
+    }
+    if let Some(tag) = strukt.tags.first() {
+        return Err(syn::Error::new_spanned(
+            &tag.name,
+            "#[derive(InfluxDbFields)] only supports rename_all",
+        ));
+    }
+    if let Some(path) = &strukt.default_tags {
+        return Err(syn::Error::new_spanned(
+            path,
+            "#[derive(InfluxDbFields)] only supports rename_all",
+        ));
+    }
+    if let Some(select) = &strukt.select {
+        return Err(syn::Error::new_spanned(
+            select,
+            "#[derive(InfluxDbFields)] only supports rename_all",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => parse_fields(&input.ident, data.fields.clone())?,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "#[derive(InfluxDbFields)] only supports structs with named fields",
+            ))
+        }
+    };
+    if let Some(wf) = fields.iter().find(|wf| wf.is_time || wf.is_measurement) {
+        return Err(syn::Error::new_spanned(
+            &wf.ident,
+            "#[derive(InfluxDbFields)] does not support time and measurement fields, \
+             use #[derive(InfluxDbWriteable)] instead",
+        ));
+    }
+
+    Ok(fields_impl(
+        &input,
+        fields_bodies(&fields, strukt.rename_all)?,
+    ))
+}